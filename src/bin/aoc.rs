@@ -0,0 +1,253 @@
+use std::{env, error::Error, fs::{self, File}, io::{self, BufWriter, Read, Write}, ops::RangeInclusive, path::{Path, PathBuf}, process::ExitCode};
+
+use aoc_2023::{days::{self, day20, day24, Day}, Solution};
+
+const USAGE: &str = "\
+usage: aoc run <DAY> [--part <PART>] [--input <FILE>] [--render] [--vcd <FILE>] [--area <MIN>,<MAX>]
+       aoc run --all [--fixtures <DIR>]";
+
+enum Command {
+    Run { day: &'static Day, part: Option<u32>, input: Option<PathBuf>, render: bool, vcd: Option<PathBuf>, area: Option<RangeInclusive<i64>> },
+    RunAll { fixtures: PathBuf },
+}
+
+impl Command {
+    fn parse(mut args: impl Iterator<Item=String>) -> Result<Self, String> {
+        match args.next().as_deref() {
+            Some("run") => {},
+            Some(other) => return Err(format!("unrecognized command `{}`", other)),
+            None => return Err("missing command".to_string()),
+        }
+
+        let mut day = None;
+        let mut part = None;
+        let mut input = None;
+        let mut all = false;
+        let mut render = false;
+        let mut vcd = None;
+        let mut area = None;
+        let mut fixtures = PathBuf::from("fixtures");
+
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--all" => { all = true },
+                "--render" => { render = true },
+                "--part" => {
+                    let value = args.next().ok_or("missing value for `--part`")?;
+
+                    part = Some(value.parse::<u32>().map_err(|_| format!("invalid part `{}`", value))?);
+                },
+                "--input" => { input = Some(PathBuf::from(args.next().ok_or("missing value for `--input`")?)) },
+                "--vcd" => { vcd = Some(PathBuf::from(args.next().ok_or("missing value for `--vcd`")?)) },
+                "--area" => {
+                    let value = args.next().ok_or("missing value for `--area`")?;
+                    let bounds = value.split_once(',').and_then(|(min, max)| Some((min.parse::<i64>().ok()?, max.parse::<i64>().ok()?)));
+
+                    area = Some(bounds.map(|(min, max)| min..=max).ok_or(format!("invalid area `{}`", value))?);
+                },
+                "--fixtures" => { fixtures = PathBuf::from(args.next().ok_or("missing value for `--fixtures`")?) },
+                _ if day.is_none() && !arg.starts_with('-') => {
+                    let number = arg.parse::<u32>().map_err(|_| format!("invalid day `{}`", arg))?;
+
+                    day = Some(days::get(number).ok_or(format!("no solver for day {}", number))?);
+                },
+                _ => return Err(format!("unexpected argument `{}`", arg)),
+            }
+        }
+
+        match (day, all) {
+            (Some(day), false) if vcd.is_some() && day.number != 20 => Err("`--vcd` is only supported by day 20".to_string()),
+            (Some(day), false) if area.is_some() && day.number != 24 => Err("`--area` is only supported by day 24".to_string()),
+            (Some(day), false) => Ok(Self::Run { day, part, input, render, vcd, area }),
+            (None, true) if part.is_none() && input.is_none() && !render && vcd.is_none() && area.is_none() => Ok(Self::RunAll { fixtures }),
+            (None, true) => Err("`--all` cannot be combined with `--part`, `--input`, `--render`, `--vcd` or `--area`".to_string()),
+            (Some(_), true) => Err("`--all` cannot be combined with a day".to_string()),
+            (None, false) => Err("missing day".to_string()),
+        }
+    }
+}
+
+fn read_input(path: Option<&PathBuf>) -> Result<String, String> {
+    match path {
+        Some(path) => fs::read_to_string(path).map_err(|err| format!("could not read `{}`: {}", path.display(), err)),
+        None => {
+            let mut input = String::new();
+
+            io::stdin().read_to_string(&mut input).map_err(|err| format!("could not read stdin: {}", err))?;
+            Ok(input)
+        },
+    }
+}
+
+/// Writes the pulses sent during the part 1 button presses of day 20 as a VCD
+/// waveform, for viewing in GTKWave.
+fn write_waveform(input: &str, path: &Path) -> Result<(), String> {
+    let modules = day20::Day20::parse(input).map_err(|err| format!("day 20: {}", err))?;
+    let trace = day20::Simulator::new(modules).press_n(day20::PRESSES);
+    let file = File::create(path).map_err(|err| format!("could not create `{}`: {}", path.display(), err))?;
+    let mut out = BufWriter::new(file);
+
+    day20::write_vcd(&trace, &mut out)
+        .and_then(|()| out.flush())
+        .map_err(|err| format!("could not write `{}`: {}", path.display(), err))
+}
+
+/// Solves part 1 of day 24, and draws it if asked to, looking for crossings
+/// within `area` instead of the puzzle's test area.
+fn solve_in_area(input: &str, area: RangeInclusive<i64>, render: bool) -> Result<(String, Option<String>), Box<dyn Error>> {
+    let storm = day24::Day24::parse(input)?.with_area(area);
+    let answer = day24::Day24::part1(&storm)?.to_string();
+    let drawing = if render { day24::Day24::render(&storm, 1).transpose()? } else { None };
+
+    Ok((answer, drawing))
+}
+
+fn run(day: &Day, part: Option<u32>, input: Option<PathBuf>, render: bool, vcd: Option<PathBuf>, area: Option<RangeInclusive<i64>>) -> Result<(), String> {
+    let input = read_input(input.as_ref())?;
+
+    let parts = match part {
+        Some(part) => vec! [part],
+        None => (1..=2).filter(|&part| day.part(part).is_some()).collect(),
+    };
+
+    for part in parts {
+        let solver = day.part(part).ok_or(format!("day {} has no part {}", day.number, part))?;
+        let (answer, drawing) = match (&area, part) {
+            (Some(area), 1) => solve_in_area(&input, area.clone(), render),
+            _ => solver(&input).and_then(|answer| Ok((answer, if render { (day.render)(&input, part)? } else { None }))),
+        }.map_err(|err| format!("day {:02}, part {}: {}", day.number, part, err))?;
+
+        println!("{}", answer);
+
+        if render {
+            println!("{}", drawing.ok_or(format!("day {} has nothing to render", day.number))?);
+        }
+    }
+
+    if let Some(path) = vcd {
+        write_waveform(&input, &path)?;
+    }
+
+    Ok(())
+}
+
+fn run_all(fixtures: PathBuf) -> Result<(), String> {
+    let mut num_failed = 0;
+
+    for day in days::DAYS.iter() {
+        let path = fixtures.join(format!("{:02}.txt", day.number));
+
+        if !path.exists() {
+            eprintln!("day {:02}: skipped, `{}` not found", day.number, path.display());
+            continue;
+        }
+
+        let input = read_input(Some(&path))?;
+
+        for (part, solver) in [(1, Some(day.part1)), (2, day.part2)] {
+            let Some(solver) = solver else { continue };
+
+            match solver(&input) {
+                Ok(answer) => println!("day {:02}, part {}: {}", day.number, part, answer),
+                Err(err) => {
+                    eprintln!("day {:02}, part {}: {}", day.number, part, err);
+                    num_failed += 1;
+                },
+            }
+        }
+    }
+
+    if num_failed > 0 {
+        Err(format!("{} part(s) failed", num_failed))
+    } else {
+        Ok(())
+    }
+}
+
+fn main() -> ExitCode {
+    let command = match Command::parse(env::args().skip(1)) {
+        Ok(command) => command,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            eprintln!("{}", USAGE);
+            return ExitCode::FAILURE;
+        }
+    };
+    let result = match command {
+        Command::Run { day, part, input, render, vcd, area } => run(day, part, input, render, vcd, area),
+        Command::RunAll { fixtures } => run_all(fixtures),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("error: {}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Command, String> {
+        Command::parse(args.iter().map(|s| s.to_string()))
+    }
+
+    #[test]
+    fn run() {
+        match parse(&["run", "05", "--part", "2", "--input", "fixtures/05.txt"]) {
+            Ok(Command::Run { day, part, input, render, vcd, area }) => {
+                assert_eq!(day.number, 5);
+                assert_eq!(part, Some(2));
+                assert_eq!(input, Some(PathBuf::from("fixtures/05.txt")));
+                assert!(!render);
+                assert_eq!(vcd, None);
+                assert_eq!(area, None);
+            },
+            _ => panic!("expected `run`"),
+        }
+
+        match parse(&["run", "17", "--render"]) {
+            Ok(Command::Run { day, render, .. }) => assert_eq!((day.number, render), (17, true)),
+            _ => panic!("expected `run --render`"),
+        }
+
+        match parse(&["run", "20", "--vcd", "pulses.vcd"]) {
+            Ok(Command::Run { day, vcd, .. }) => assert_eq!((day.number, vcd), (20, Some(PathBuf::from("pulses.vcd")))),
+            _ => panic!("expected `run --vcd`"),
+        }
+
+        match parse(&["run", "24", "--part", "1", "--area", "7,27"]) {
+            Ok(Command::Run { day, area, .. }) => assert_eq!((day.number, area), (24, Some(7..=27))),
+            _ => panic!("expected `run --area`"),
+        }
+    }
+
+    #[test]
+    fn run_all() {
+        match parse(&["run", "--all"]) {
+            Ok(Command::RunAll { fixtures }) => assert_eq!(fixtures, PathBuf::from("fixtures")),
+            _ => panic!("expected `run --all`"),
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["walk", "01"]).is_err());
+        assert!(parse(&["run"]).is_err());
+        assert!(parse(&["run", "26"]).is_err());
+        assert!(parse(&["run", "01", "--part"]).is_err());
+        assert!(parse(&["run", "01", "--all"]).is_err());
+        assert!(parse(&["run", "--all", "--part", "1"]).is_err());
+        assert!(parse(&["run", "--all", "--render"]).is_err());
+        assert!(parse(&["run", "--all", "--vcd", "pulses.vcd"]).is_err());
+        assert!(parse(&["run", "05", "--vcd", "pulses.vcd"]).is_err());
+        assert!(parse(&["run", "20", "--vcd"]).is_err());
+        assert!(parse(&["run", "24", "--area", "7..27"]).is_err());
+        assert!(parse(&["run", "20", "--area", "7,27"]).is_err());
+        assert!(parse(&["run", "--all", "--area", "7,27"]).is_err());
+    }
+}
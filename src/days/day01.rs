@@ -1,9 +1,9 @@
-use std::io::{self, BufRead};
+use crate::{prelude::{ParseError, SolveError}, Solution};
 
 fn pluck_spelled_out_digits(s: &str) -> Vec<u32> {
     let mut result = vec! [];
 
-    for (i, ch) in s.chars().enumerate() {
+    for (i, ch) in s.char_indices() {
         if s[i..].starts_with("one") {
             result.push(1);
         } else if s[i..].starts_with("two") {
@@ -37,7 +37,6 @@ fn pluck_spelled_out_digits(s: &str) -> Vec<u32> {
 fn pluck_digits(s: &str) -> Vec<u32> {
     s.chars()
         .filter_map(|ch| ch.to_digit(10))
-        .map(|d| d as u32)
         .collect::<Vec<_>>()
 }
 
@@ -45,12 +44,24 @@ fn parse_calibration_value(digits: &[u32]) -> u32 {
     10 * digits[0] + digits[digits.len() - 1]
 }
 
-fn main() {
-    let stdin = io::stdin().lock();
-    let lines = stdin.lines().filter_map(Result::ok).collect::<Vec<_>>();
+pub struct Day01;
 
-    println!("{}", lines.iter().map(|line| parse_calibration_value(&pluck_digits(&line))).sum::<u32>());
-    println!("{}", lines.iter().map(|line| parse_calibration_value(&pluck_spelled_out_digits(&line))).sum::<u32>());
+impl Solution for Day01 {
+    type Input = Vec<String>;
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(lines: &Self::Input) -> Result<u32, SolveError> {
+        Ok(lines.iter().map(|line| parse_calibration_value(&pluck_digits(line))).sum::<u32>())
+    }
+
+    fn part2(lines: &Self::Input) -> Result<u32, SolveError> {
+        Ok(lines.iter().map(|line| parse_calibration_value(&pluck_spelled_out_digits(line))).sum::<u32>())
+    }
 }
 
 #[cfg(test)]
@@ -66,8 +77,8 @@ mod tests {
             "treb7uchet",
         ];
 
-        assert_eq!(lines.iter().map(|line| parse_calibration_value(&pluck_digits(&line))).collect::<Vec<_>>(), &[12, 38, 15, 77]);
-        assert_eq!(lines.iter().map(|line| parse_calibration_value(&pluck_digits(&line))).sum::<u32>(), 142);
+        assert_eq!(lines.iter().map(|line| parse_calibration_value(&pluck_digits(line))).collect::<Vec<_>>(), &[12, 38, 15, 77]);
+        assert_eq!(lines.iter().map(|line| parse_calibration_value(&pluck_digits(line))).sum::<u32>(), 142);
     }
 
     #[test]
@@ -82,8 +93,8 @@ mod tests {
             "7pqrstsixteen"
         ];
 
-        assert_eq!(lines.iter().map(|line| parse_calibration_value(&pluck_spelled_out_digits(&line))).collect::<Vec<_>>(), &[29, 83, 13, 24, 42, 14, 76]);
-        assert_eq!(lines.iter().map(|line| parse_calibration_value(&pluck_spelled_out_digits(&line))).sum::<u32>(), 281);
+        assert_eq!(lines.iter().map(|line| parse_calibration_value(&pluck_spelled_out_digits(line))).collect::<Vec<_>>(), &[29, 83, 13, 24, 42, 14, 76]);
+        assert_eq!(lines.iter().map(|line| parse_calibration_value(&pluck_spelled_out_digits(line))).sum::<u32>(), 281);
     }
 
     #[test]
@@ -1,7 +1,7 @@
-use std::io::{self, BufRead};
-
 use sscanf::scanf;
 
+use crate::{prelude::*, Solution};
+
 #[derive(Default)]
 struct GameSet {
     red: usize,
@@ -10,10 +10,10 @@ struct GameSet {
 }
 
 impl GameSet {
-    fn parse(line: &str) -> GameSet {
-        line.split(",")
+    fn parse(line: &str, set: &str) -> Result<GameSet, ParseError> {
+        set.split(',')
             .map(|part| part.trim())
-            .fold(
+            .try_fold(
                 GameSet { blue: 0, red: 0, green: 0 },
                 |mut game_set, part| {
                     if let Ok(red) = scanf!(part, "{usize} red") {
@@ -22,9 +22,11 @@ impl GameSet {
                         game_set.green = green;
                     } else if let Ok(blue) = scanf!(part, "{usize} blue") {
                         game_set.blue = blue;
+                    } else {
+                        return Err(ParseError::new(line, part, "`<n> red`, `<n> green` or `<n> blue`"));
                     }
 
-                    game_set
+                    Ok(game_set)
                 }
             )
     }
@@ -46,19 +48,20 @@ impl GameSet {
     }
 }
 
-struct Game {
+pub struct Game {
     id: usize,
     sets: Vec<GameSet>,
 }
 
 impl Game {
-    fn parse(line: &str) -> Option<Game> {
-        let (id, rest) = scanf!(line, "Game {usize}: {String}").ok()?;
-        let sets = rest.split(";")
-            .map(|set| GameSet::parse(set.trim()))
-            .collect();
-
-        Some(Game { id, sets })
+    fn parse(line: &str) -> Result<Game, ParseError> {
+        let (header, rest) = line.split_once(':').ok_or_else(|| ParseError::new(line, line, "`Game <id>: <sets>`"))?;
+        let id = scanf!(header, "Game {usize}").map_err(|_| ParseError::new(line, header, "`Game <id>`"))?;
+        let sets = rest.split(';')
+            .map(|set| GameSet::parse(line, set.trim()))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Game { id, sets })
     }
 
     fn iter(&self) -> impl Iterator<Item=&GameSet> + '_ {
@@ -70,20 +73,31 @@ impl Game {
     }
 }
 
-fn main() {
-    let stdin = io::stdin().lock();
-    let lines = stdin.lines().filter_map(Result::ok).collect::<Vec<_>>();
-    let games = lines.iter().filter_map(|line| Game::parse(&line)).collect::<Vec<_>>();
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    println!("{}", games.iter().filter(|game| game.is_feasible(12, 13, 14)).map(|game| game.id).sum::<usize>());
-    println!("{}", games.iter().map(|game| game.iter().fold(GameSet::default(), |acc, game_set| acc.max(game_set)).power()).sum::<usize>());
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Game::parse)
+    }
+
+    fn part1(games: &Self::Input) -> Result<usize, SolveError> {
+        Ok(games.iter().filter(|game| game.is_feasible(12, 13, 14)).map(|game| game.id).sum::<usize>())
+    }
+
+    fn part2(games: &Self::Input) -> Result<usize, SolveError> {
+        Ok(games.iter().map(|game| game.iter().fold(GameSet::default(), |acc, game_set| acc.max(game_set)).power()).sum::<usize>())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    const LINES: [&'static str; 5] = [
+    const LINES: [&str; 5] = [
         "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
         "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue",
         "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red",
@@ -94,18 +108,27 @@ mod tests {
     #[test]
     fn _01() {
         assert_eq!(
-            LINES.iter().filter_map(|line| Game::parse(line)).filter(|game| game.is_feasible(12, 13, 14)).map(|game| game.id).sum::<usize>(),
+            LINES.iter().map(|line| Game::parse(line).unwrap()).filter(|game| game.is_feasible(12, 13, 14)).map(|game| game.id).sum::<usize>(),
             8
         );
     }
 
     #[test]
     fn _02() {
-        let games = LINES.iter().filter_map(|line| Game::parse(line)).collect::<Vec<_>>();
+        let games = LINES.iter().map(|line| Game::parse(line).unwrap()).collect::<Vec<_>>();
 
         assert_eq!(
             games.iter().map(|game| game.iter().fold(GameSet::default(), |acc, game_set| acc.max(game_set)).power()).sum::<usize>(),
             2286
         );
     }
+
+    #[test]
+    fn parse_error() {
+        let err = Day02::parse("Game 1: 3 blue\nGame 2: 1 blue, 2 grene").err().unwrap();
+
+        assert_eq!(err.line(), 2);
+        assert_eq!(err.column(), 17);
+        assert_eq!(err.text(), "2 grene");
+    }
 }
@@ -0,0 +1,99 @@
+use crate::{prelude::*, Solution};
+
+fn try_parse_number_at(grid: &Grid<char>, point: Point2<i32>) -> Option<(i32, i32, usize)> {
+    if !grid.get(point)?.is_ascii_digit() {
+        return None;
+    }
+
+    let is_digit = |x: i32| grid.get(Point2::new(x, point.y)).is_some_and(|ch| ch.is_ascii_digit());
+    let lower = (0..=point.x).rev().take_while(|&x| is_digit(x)).last().unwrap();
+    let upper = (point.x..grid.cols() as i32).take_while(|&x| is_digit(x)).last().unwrap();
+
+    grid.row(point.y as usize)[lower as usize..=upper as usize].iter().collect::<String>().parse::<usize>().ok()
+        .map(|number| (point.y, lower, number))
+}
+
+fn parse_numbers_adjacent_to(grid: &Grid<char>, point: Point2<i32>) -> Vec<(i32, i32, usize)> {
+    grid.neighbours8(point)
+        .filter_map(|(point, _)| try_parse_number_at(grid, point))
+        .collect::<Vec<_>>()
+}
+
+fn parse_part_numbers(grid: &Grid<char>) -> impl Iterator<Item=usize> + '_ {
+    let mut part_numbers = grid.iter()
+        .filter(|&(_, &ch)| ch != '.' && !ch.is_ascii_digit())
+        .flat_map(|(point, _)| parse_numbers_adjacent_to(grid, point))
+        .collect::<Vec<_>>();
+
+    part_numbers.sort_unstable();
+    part_numbers.dedup();
+    part_numbers.into_iter().map(|(_i, _j, number)| number)
+}
+
+fn parse_gears(grid: &Grid<char>) -> impl Iterator<Item=Vec<usize>> + '_ {
+    grid.iter()
+        .filter(|&(_, &ch)| ch == '*')
+        .filter_map(|(point, _)| {
+            let mut numbers = parse_numbers_adjacent_to(grid, point);
+            numbers.sort_unstable();
+            numbers.dedup();
+
+            if numbers.len() == 2 {
+                Some(numbers.into_iter().map(|(_i, _j, number)| number).collect::<Vec<_>>())
+            } else {
+                None
+            }
+        })
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Grid<char>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input.lines(), Some, "a character")
+    }
+
+    fn part1(grid: &Self::Input) -> Result<usize, SolveError> {
+        Ok(parse_part_numbers(grid).sum::<usize>())
+    }
+
+    fn part2(grid: &Self::Input) -> Result<usize, SolveError> {
+        Ok(parse_gears(grid).map(|numbers| numbers.iter().product::<usize>()).sum::<usize>())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const LINES: [&str; 10] = [
+        "467..114..",
+        "...*......",
+        "..35..633.",
+        "......#...",
+        "617*......",
+        ".....+.58.",
+        "..592.....",
+        "......755.",
+        "...$.*....",
+        ".664.598..",
+    ];
+
+    #[test]
+    fn _01() {
+        let grid = Grid::parse(LINES, Some, "a character").unwrap();
+
+        assert_eq!(parse_part_numbers(&grid).sum::<usize>(), 4361);
+    }
+
+    #[test]
+    fn _02() {
+        let grid = Grid::parse(LINES, Some, "a character").unwrap();
+
+        assert_eq!(parse_gears(&grid).map(|numbers| numbers.iter().product::<usize>()).sum::<usize>(), 467835);
+    }
+}
@@ -0,0 +1,123 @@
+use crate::{prelude::*, Solution};
+
+pub struct Scratchcard {
+    id: usize,
+    numbers: Vec<usize>,
+    winning_numbers: Vec<usize>,
+}
+
+impl Scratchcard {
+    fn parse(line: &str) -> Result<Self, ParseError> {
+        let (header, rest) = line.split_once(':').ok_or_else(|| ParseError::new(line, line, "`Card <id>: <numbers> | <numbers>`"))?;
+        let id = header.strip_prefix("Card").ok_or_else(|| ParseError::new(line, header, "`Card <id>`"))?;
+        let (winning_numbers, numbers) = rest.split_once('|').ok_or_else(|| ParseError::new(line, rest, "`<numbers> | <numbers>`"))?;
+        let parse_numbers = |numbers: &str| {
+            numbers.split_whitespace().map(|s| parse_token(line, s, "a number")).collect::<Result<Vec<_>, _>>()
+        };
+
+        Ok(Self {
+            id: parse_token(line, id.trim(), "a card id")?,
+            numbers: parse_numbers(numbers)?,
+            winning_numbers: parse_numbers(winning_numbers)?,
+        })
+    }
+
+    fn id(&self) -> usize {
+        self.id
+    }
+
+    fn matching_numbers(&self) -> usize {
+        self.numbers.iter().filter(|n| self.winning_numbers.contains(n)).count()
+    }
+
+    fn score(&self) -> usize {
+        let n = self.matching_numbers();
+
+        if n > 0 {
+            2_i32.pow(n as u32 - 1) as usize
+        } else {
+            0
+        }
+    }
+}
+
+fn total_scratchcards(scatchcards: &[Scratchcard]) -> usize {
+    let max_id = scatchcards.iter().map(Scratchcard::id).max().unwrap_or(0);
+    let mut copies = vec! [0; max_id + 1];
+
+    for scratchcard in scatchcards {
+        let matching_numbers = scratchcard.matching_numbers();
+        let id = scratchcard.id();
+
+        copies[id] += 1;
+        // copies never go past the last card
+        for i in (id + 1)..=(id + matching_numbers).min(max_id) {
+            copies[i] += copies[id];
+        }
+    }
+
+    copies.iter().sum()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Scratchcard>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Scratchcard::parse)
+    }
+
+    fn part1(scratchcards: &Self::Input) -> Result<usize, SolveError> {
+        Ok(scratchcards.iter().map(|s| s.score()).sum::<usize>())
+    }
+
+    fn part2(scratchcards: &Self::Input) -> Result<usize, SolveError> {
+        Ok(total_scratchcards(scratchcards))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINES: [&str; 6] = [
+        "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53",
+        "Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19",
+        "Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1",
+        "Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83",
+        "Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36",
+        "Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11",
+    ];
+
+    #[test]
+    fn _01() {
+        let scratchcards = LINES.iter().map(|line| Scratchcard::parse(line).unwrap()).collect::<Vec<_>>();
+
+        assert_eq!(scratchcards.iter().map(|s| s.score()).sum::<usize>(), 13);
+    }
+
+    #[test]
+    fn _02() {
+        let scratchcards = LINES.iter().map(|line| Scratchcard::parse(line).unwrap()).collect::<Vec<_>>();
+
+        assert_eq!(total_scratchcards(&scratchcards), 30);
+    }
+
+    #[test]
+    fn _02_edge_cases() {
+        let past_the_end = Day04::parse("Card 1: 1 2 | 1 2\nCard 2: 3 4 | 3 4").unwrap();
+
+        assert_eq!(total_scratchcards(&[]), 0);
+        assert_eq!(total_scratchcards(&past_the_end), 3);
+    }
+
+    #[test]
+    fn parse_error() {
+        let err = Day04::parse("Card 1: 41 48 | 83 B6").err().unwrap();
+
+        assert_eq!((err.line(), err.column(), err.text()), (1, 20, "B6"));
+    }
+}
@@ -0,0 +1,479 @@
+use std::{collections::HashMap, ops::Range};
+
+use sscanf::sscanf;
+
+use crate::{prelude::*, Solution};
+
+#[derive(Clone, sscanf::FromScanf)]
+#[sscanf(format = "{dst_start} {src_start} {length}")]
+struct RangeConverter {
+    dst_start: usize,
+    src_start: usize,
+    length: usize,
+}
+
+impl RangeConverter {
+    fn parse(line: &str) -> Result<Self, ParseError> {
+        let converter = sscanf!(line, "{RangeConverter}").map_err(|_| ParseError::new(line, line, "`<destination start> <source start> <length>`"))?;
+
+        if converter.src_start.checked_add(converter.length).is_none() || converter.dst_start.checked_add(converter.length).is_none() {
+            return Err(ParseError::new(line, line, "ranges that end before `usize::MAX`"));
+        }
+
+        Ok(converter)
+    }
+
+    fn source(&self) -> Range<usize> {
+        self.src_start..self.src_start.saturating_add(self.length)
+    }
+
+    fn apply(&self, value: usize) -> usize {
+        self.dst_start.saturating_add(value - self.src_start)
+    }
+
+    fn shift(&self, range: &Range<usize>) -> Range<usize> {
+        self.apply(range.start)..self.apply(range.end)
+    }
+
+    fn between(source: &Range<usize>, dst_start: usize) -> Self {
+        Self { dst_start, src_start: source.start, length: source.len() }
+    }
+
+    fn reversed(&self) -> Self {
+        Self { dst_start: self.src_start, src_start: self.dst_start, length: self.length }
+    }
+}
+
+/// A piecewise shift from one category to another.
+pub struct Map {
+    src: String,
+    dst: String,
+    converters: RangeMap<usize, RangeConverter>,
+}
+
+impl Map {
+    fn identity(category: &str) -> Self {
+        Self { src: category.to_string(), dst: category.to_string(), converters: RangeMap::new() }
+    }
+
+    pub fn src(&self) -> &str {
+        &self.src
+    }
+
+    pub fn dst(&self) -> &str {
+        &self.dst
+    }
+
+    fn pieces(&self) -> impl Iterator<Item=(Range<usize>, RangeConverter)> + '_ {
+        let domain = 0..usize::MAX;
+        let identities = self.converters.gaps(domain.clone())
+            .map(|gap| (gap.clone(), RangeConverter::between(&gap, gap.start)));
+
+        self.converters.overlapping(domain)
+            .map(|(range, converter)| (range, converter.clone()))
+            .chain(identities)
+    }
+
+    pub fn get(&self, value: usize) -> usize {
+        self.converters.get(&value).map_or(value, |converter| converter.apply(value))
+    }
+
+    pub fn translate(&self, ranges: &RangeSet<usize>) -> RangeSet<usize> {
+        self.converters.map_set(ranges, |range, converter| converter.shift(&range))
+    }
+
+    pub fn then(&self, next: &Map) -> Map {
+        let mut converters = RangeMap::new();
+
+        for (range, converter) in self.pieces() {
+            let image = converter.shift(&range);
+            let back = converter.reversed();
+            let next_pieces = next.converters.overlapping(image.clone())
+                .map(|(overlap, next_converter)| (overlap.clone(), next_converter.shift(&overlap).start))
+                .chain(next.converters.gaps(image).map(|gap| (gap.clone(), gap.start)));
+
+            for (overlap, dst_start) in next_pieces {
+                let source = back.shift(&overlap);
+
+                if source.start != dst_start {
+                    converters.insert(source.clone(), RangeConverter::between(&source, dst_start));
+                }
+            }
+        }
+
+        Map { src: self.src.clone(), dst: next.dst.clone(), converters }
+    }
+
+    pub fn inverse(&self) -> InverseMap {
+        InverseMap { converters: self.pieces().map(|(_, converter)| converter.reversed()).collect() }
+    }
+}
+
+pub struct InverseMap {
+    converters: Vec<RangeConverter>,
+}
+
+impl InverseMap {
+    pub fn translate(&self, ranges: &RangeSet<usize>) -> RangeSet<usize> {
+        self.converters.iter()
+            .flat_map(|converter| {
+                ranges.intersection(&RangeSet::from(converter.source())).into_iter().map(|range| converter.shift(&range))
+            })
+            .collect()
+    }
+}
+
+pub struct Almanac {
+    seeds: Vec<usize>,
+    maps: HashMap<String, Map>,
+    chain: Vec<String>,
+    seed_to_location: Map,
+    location_to_seed: InverseMap,
+}
+
+struct Header {
+    line_number: usize,
+    line: String,
+    src: String,
+}
+
+impl Header {
+    fn error(&self, expected: impl Into<String>) -> ParseError {
+        ParseError::new(&self.line, &self.line[..self.src.len()], expected).at_line(self.line_number)
+    }
+}
+
+impl Almanac {
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self, ParseError> {
+        let mut maps = HashMap::new();
+        let mut seeds = vec! [];
+        let mut headers = vec! [];
+        let mut src = None;
+
+        for (i, line) in lines.enumerate() {
+            if line.is_empty() {
+                // pass
+            } else if let Some(seeds_) = line.strip_prefix("seeds:") {
+                seeds = seeds_.split_whitespace()
+                    .map(|s| parse_token(&line, s, "a seed number"))
+                    .collect::<Result<_, _>>()
+                    .map_err(|err| err.at_line(i + 1))?;
+
+                if seeds.is_empty() {
+                    return Err(ParseError::new(&line, seeds_.trim(), "a seed number").at_line(i + 1));
+                }
+            } else if let Ok((src_, dst)) = sscanf!(line, "{String}-to-{String} map:") {
+                let header = Header { line_number: i + 1, line: line.clone(), src: src_.clone() };
+
+                if maps.contains_key(&src_) {
+                    return Err(header.error("a category that has no map yet"));
+                }
+
+                maps.insert(src_.clone(), Map { src: src_.clone(), dst, converters: RangeMap::new() });
+                headers.push(header);
+                src = Some(src_);
+            } else if let Some(map) = src.as_ref().and_then(|src| maps.get_mut(src)) {
+                let converter = RangeConverter::parse(&line).map_err(|err| err.at_line(i + 1))?;
+
+                map.converters.insert(converter.source(), converter);
+            } else {
+                return Err(ParseError::new(&line, &line, "`seeds: <numbers>` or `<source>-to-<destination> map:`").at_line(i + 1));
+            }
+        }
+
+        if seeds.is_empty() {
+            return Err(ParseError::end_of_input("`seeds: <numbers>`"));
+        }
+
+        let chain = Self::category_chain(&maps, &headers)?;
+        let seed_to_location = Self::composed(&maps, &chain);
+        let location_to_seed = seed_to_location.inverse();
+
+        Ok(Self { seeds, maps, chain, seed_to_location, location_to_seed })
+    }
+
+    fn category_chain(maps: &HashMap<String, Map>, headers: &[Header]) -> Result<Vec<String>, ParseError> {
+        let mut starts = headers.iter().filter(|header| maps.values().all(|map| map.dst != header.src));
+        let Some(start) = starts.next() else {
+            return match headers.first() {
+                Some(header) => Err(header.error("a category chain without cycles")),
+                None => Ok(vec! []),
+            };
+        };
+        let mut chain = vec! [start.src.clone()];
+
+        while let Some(map) = maps.get(chain.last().unwrap()) {
+            if chain.contains(&map.dst) {
+                let header = headers.iter().find(|header| header.src == map.src).unwrap();
+
+                return Err(header.error("a category chain without cycles"));
+            }
+
+            chain.push(map.dst.clone());
+        }
+
+        if let Some(header) = starts.next() {
+            return Err(header.error(format!("a map from `{}`", chain.last().unwrap())));
+        } else if let Some(header) = headers.iter().find(|header| !chain.contains(&header.src)) {
+            return Err(header.error("a category chain without cycles"));
+        }
+
+        Ok(chain)
+    }
+
+    pub fn categories(&self) -> &[String] {
+        &self.chain
+    }
+
+    fn composed(maps: &HashMap<String, Map>, categories: &[String]) -> Map {
+        let mut composed = Map::identity(categories.first().map_or("seed", String::as_str));
+
+        for category in &categories[..categories.len().saturating_sub(1)] {
+            composed = composed.then(&maps[category]);
+        }
+
+        composed
+    }
+
+    /// The single map from category `src` to a later category `dst`.
+    pub fn query(&self, src: &str, dst: &str) -> Result<Map, SolveError> {
+        let position = |category: &str| {
+            self.chain.iter().position(|other| other == category).ok_or_else(|| SolveError::MissingReference(category.to_string()))
+        };
+        let (start, end) = (position(src)?, position(dst)?);
+
+        if end < start {
+            return Err(SolveError::UnreachableTarget(format!("`{}` from `{}`", dst, src)));
+        }
+
+        Ok(Self::composed(&self.maps, &self.chain[start..=end]))
+    }
+
+    fn seeds(&self) -> &[usize] {
+        &self.seeds
+    }
+
+    fn seed_ranges(&self) -> Result<RangeSet<usize>, SolveError> {
+        let mut seed_ranges = RangeSet::new();
+
+        for pair in self.seeds.chunks(2) {
+            let &[start, length] = pair else {
+                return Err(SolveError::MissingValue(format!("the length of the seed range from {}", pair[0])));
+            };
+            let end = start.checked_add(length).ok_or_else(|| SolveError::Overflow(format!("the seed range from {}", start)))?;
+
+            seed_ranges.push(start..end);
+        }
+
+        Ok(seed_ranges)
+    }
+
+    pub fn seed_to_location(&self) -> &Map {
+        &self.seed_to_location
+    }
+
+    pub fn location_to_seed(&self) -> &InverseMap {
+        &self.location_to_seed
+    }
+
+    pub fn translate(&self, seed: Range<usize>) -> RangeSet<usize> {
+        self.seed_to_location.translate(&RangeSet::from(seed))
+    }
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Almanac::parse(input.lines().map(str::to_string))
+    }
+
+    fn part1(almanac: &Self::Input) -> Result<usize, SolveError> {
+        let seed_to_location = almanac.seed_to_location();
+
+        almanac.seeds().iter()
+            .map(|&seed| seed_to_location.get(seed))
+            .min()
+            .ok_or_else(|| SolveError::UnreachableTarget("a location for any seed".to_string()))
+    }
+
+    fn part2(almanac: &Self::Input) -> Result<usize, SolveError> {
+        almanac.seed_to_location().translate(&almanac.seed_ranges()?).iter()
+            .next()
+            .map(|range| range.start)
+            .ok_or_else(|| SolveError::UnreachableTarget("a location for any seed".to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINES: [&str; 33] = [
+        "seeds: 79 14 55 13",
+        "",
+        "seed-to-soil map:",
+        "50 98 2",
+        "52 50 48",
+        "",
+        "soil-to-fertilizer map:",
+        "0 15 37",
+        "37 52 2",
+        "39 0 15",
+        "",
+        "fertilizer-to-water map:",
+        "49 53 8",
+        "0 11 42",
+        "42 0 7",
+        "57 7 4",
+        "",
+        "water-to-light map:",
+        "88 18 7",
+        "18 25 70",
+        "",
+        "light-to-temperature map:",
+        "45 77 23",
+        "81 45 19",
+        "68 64 13",
+        "",
+        "temperature-to-humidity map:",
+        "0 69 1",
+        "1 0 69",
+        "",
+        "humidity-to-location map:",
+        "60 56 37",
+        "56 93 4",
+    ];
+
+    #[test]
+    fn _01() {
+        let almanac = Almanac::parse(LINES.iter().map(|s| s.to_string())).unwrap();
+
+        assert_eq!(
+            almanac.seeds().iter()
+                .flat_map(|&seed| almanac.translate(seed..(seed + 1)))
+                .map(|r| r.start)
+                .collect::<Vec<_>>(),
+            vec! [82, 43, 86, 35]
+        );
+        assert_eq!(
+            almanac.seeds().iter()
+                .flat_map(|&seed| almanac.translate(seed..(seed + 1)))
+                .map(|range| range.start)
+                .min().unwrap(),
+            35
+        );
+    }
+
+    #[test]
+    fn _02() {
+        let almanac = Almanac::parse(LINES.iter().map(|s| s.to_string())).unwrap();
+
+        assert_eq!(
+            almanac.seed_ranges().unwrap().iter()
+                .map(|seed_range| almanac.translate(seed_range.clone()))
+                .flat_map(|ranges| ranges.into_iter().map(|range| range.start))
+                .min().unwrap(),
+            46
+        );
+    }
+
+    #[test]
+    fn composed() {
+        let almanac = Almanac::parse(LINES.iter().map(|s| s.to_string())).unwrap();
+        let seed_to_location = almanac.seed_to_location();
+        let location_to_seed = almanac.location_to_seed();
+
+        for seed in 0..120 {
+            let mut value = seed;
+            let mut name = "seed".to_string();
+
+            while let Some(map) = almanac.maps.get(&name) {
+                value = map.get(value);
+                name = map.dst.clone();
+            }
+
+            assert_eq!(seed_to_location.get(seed), value, "seed {}", seed);
+            assert!(location_to_seed.translate(&RangeSet::from(value..value + 1)).contains(seed), "seed {}", seed);
+        }
+
+        assert_eq!(seed_to_location.dst, "location");
+        assert!(location_to_seed.translate(&RangeSet::from(35..36)).contains(13));
+    }
+
+    #[test]
+    fn query() {
+        let almanac = Almanac::parse(LINES.iter().map(|s| s.to_string())).unwrap();
+        let soil_to_humidity = almanac.query("soil", "humidity").unwrap();
+
+        assert_eq!(
+            almanac.categories(),
+            &["seed", "soil", "fertilizer", "water", "light", "temperature", "humidity", "location"]
+        );
+        assert_eq!((soil_to_humidity.src(), soil_to_humidity.dst()), ("soil", "humidity"));
+        assert_eq!(soil_to_humidity.get(81), 78);
+        assert_eq!(almanac.query("light", "light").unwrap().get(74), 74);
+        assert_eq!(almanac.query("humidity", "soil").err(), Some(SolveError::UnreachableTarget("`soil` from `humidity`".to_string())));
+        assert_eq!(almanac.query("seed", "colour").err(), Some(SolveError::MissingReference("colour".to_string())));
+    }
+
+    #[test]
+    fn other_categories() {
+        let almanac = Day05::parse("seeds: 1 5\n\nb-to-c map:\n10 0 5\n\na-to-b map:\n0 1 5\n").unwrap();
+
+        assert_eq!(almanac.categories(), &["a", "b", "c"]);
+        assert_eq!(Day05::part1(&almanac), Ok(10));
+        assert_eq!(Day05::part2(&almanac), Ok(10));
+    }
+
+    #[test]
+    fn category_errors() {
+        let error = |input: &str| Day05::parse(input).err().map(|err| (err.line(), err.text().to_string(), err.expected().to_string()));
+
+        assert_eq!(
+            error("seeds: 1\na-to-b map:\nc-to-d map:\n"),
+            Some((3, "c".to_string(), "a map from `b`".to_string()))
+        );
+        assert_eq!(
+            error("seeds: 1\na-to-b map:\nb-to-c map:\nc-to-b map:\n"),
+            Some((4, "c".to_string(), "a category chain without cycles".to_string()))
+        );
+        assert_eq!(
+            error("seeds: 1\na-to-b map:\nc-to-d map:\nd-to-c map:\n"),
+            Some((3, "c".to_string(), "a category chain without cycles".to_string()))
+        );
+        assert_eq!(
+            error("seeds: 1\na-to-b map:\na-to-c map:\n"),
+            Some((3, "a".to_string(), "a category that has no map yet".to_string()))
+        );
+    }
+
+    #[test]
+    fn parse_error() {
+        let err = Day05::parse("seeds: 79 14\n\nseed-to-soil map:\n50 98\n").err().unwrap();
+
+        assert_eq!((err.line(), err.column(), err.text()), (4, 1, "50 98"));
+    }
+
+    #[test]
+    fn seed_errors() {
+        let error = |input: &str| Day05::parse(input).err().map(|err| (err.line(), err.text().to_string()));
+
+        assert_eq!(error("seeds:\n\na-to-b map:\n"), Some((1, "".to_string())));
+        assert_eq!(error("a-to-b map:\n0 1 5\n"), Some((1, "".to_string())));
+        assert_eq!(error("seeds: 1\n\na-to-b map:\n18446744073709551615 0 1\n"), Some((4, "18446744073709551615 0 1".to_string())));
+
+        let unpaired = Day05::parse("seeds: 79 14 55\n").unwrap();
+        let empty = Day05::parse("seeds: 7 0\n").unwrap();
+        let overflowing = Day05::parse("seeds: 18446744073709551615 1\n").unwrap();
+
+        assert_eq!(Day05::part1(&unpaired), Ok(14));
+        assert_eq!(Day05::part2(&unpaired), Err(SolveError::MissingValue("the length of the seed range from 55".to_string())));
+        assert_eq!(Day05::part2(&empty), Err(SolveError::UnreachableTarget("a location for any seed".to_string())));
+        assert_eq!(Day05::part2(&overflowing), Err(SolveError::Overflow("the seed range from 18446744073709551615".to_string())));
+    }
+}
@@ -0,0 +1,112 @@
+use crate::{prelude::*, Solution};
+
+pub struct Race {
+    total_duration: usize,
+    best_distance: usize,
+}
+
+impl Race {
+    fn parse_all(lines: &[String], ignore_whitespace: bool) -> Result<Vec<Self>, ParseError> {
+        let mut distances = vec! [];
+        let mut times = vec! [];
+
+        for (i, line) in lines.iter().enumerate() {
+            let (label, rest) = line.split_once(':')
+                .ok_or_else(|| ParseError::new(line, line, "`Time: <numbers>` or `Distance: <numbers>`").at_line(i + 1))?;
+            let numbers =
+                if ignore_whitespace {
+                    rest.chars().filter(|c| !c.is_whitespace()).collect::<String>().parse::<usize>()
+                        .map(|number| vec! [number])
+                        .map_err(|_| ParseError::new(line, rest.trim(), "a number"))
+                } else {
+                    rest.split_whitespace().map(|s| parse_token(line, s, "a number")).collect::<Result<Vec<_>, _>>()
+                }.map_err(|err| err.at_line(i + 1))?;
+
+            match label {
+                "Distance" => { distances = numbers },
+                "Time" => { times = numbers },
+                _ => return Err(ParseError::new(line, label, "`Time` or `Distance`").at_line(i + 1)),
+            }
+        }
+
+        if distances.len() != times.len() {
+            return Err(ParseError::end_of_input(format!("as many distances as times ({})", times.len())).at_line(lines.len()));
+        }
+
+        Ok(distances.into_iter().zip(times)
+            .map(|(best_distance, total_duration)| Self { best_distance, total_duration })
+            .collect())
+    }
+
+    fn simulate(&self, speed: usize) -> usize {
+        let remaining_duration = self.total_duration - speed;
+
+        speed * remaining_duration
+    }
+
+    fn count(&self) -> usize {
+        (1..self.total_duration)
+            .map(|speed| self.simulate(speed))
+            .filter(|&distance| distance > self.best_distance)
+            .count()
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = (Vec<Race>, Vec<Race>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let lines = input.lines().map(str::to_string).collect::<Vec<_>>();
+
+        Ok((Race::parse_all(&lines, false)?, Race::parse_all(&lines, true)?))
+    }
+
+    fn part1((races, _): &Self::Input) -> Result<usize, SolveError> {
+        Ok(races.iter().map(Race::count).product::<usize>())
+    }
+
+    fn part2((_, races): &Self::Input) -> Result<usize, SolveError> {
+        Ok(races.iter().map(Race::count).product::<usize>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINES: [&str; 2] = [
+        "Time:      7  15   30",
+        "Distance:  9  40  200",
+    ];
+
+    #[test]
+    fn _01() {
+        let races = Race::parse_all(
+            &LINES.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
+            false
+        ).unwrap();
+
+        assert_eq!(races.iter().map(Race::count).product::<usize>(), 288);
+    }
+
+    #[test]
+    fn _02() {
+        let races = Race::parse_all(
+            &LINES.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
+            true
+        ).unwrap();
+
+        assert_eq!(races.iter().map(Race::count).product::<usize>(), 71503);
+    }
+
+    #[test]
+    fn parse_error() {
+        let err = Day06::parse("Time:      7  15   30\nDistance:  9  4O  200").err().unwrap();
+
+        assert_eq!((err.line(), err.column(), err.text()), (2, 15, "4O"));
+    }
+}
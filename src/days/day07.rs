@@ -1,4 +1,6 @@
-use std::{collections::HashMap, io::{self, BufRead}, ops::AddAssign};
+use std::{collections::HashMap, ops::AddAssign};
+
+use crate::{prelude::*, Solution};
 
 const CARDS: &str = "AKQJT98765432";
 const CARDS_WITH_JOKER: &str = "AKQT98765432J";
@@ -14,18 +16,22 @@ enum HandType {
     HighCard,
 }
 
-struct Hand {
+pub struct Hand {
     cards: Vec<char>,
     bid: usize,
 }
 
 impl Hand {
-    fn parse(line: &str) -> Option<Hand> {
+    fn parse(line: &str) -> Result<Hand, ParseError> {
         let mut parts = line.split_whitespace();
-        let cards = parts.next().unwrap().chars().collect::<Vec<_>>();
-        let bid = parts.next().unwrap().parse::<usize>().ok()?;
+        let cards = parts.next().ok_or_else(|| ParseError::new(line, line, "a hand of five cards"))?;
+        let bid = parts.next().ok_or_else(|| ParseError::new(line, &line[line.len()..], "a bid"))?;
+
+        if cards.chars().count() != 5 || !cards.chars().all(|ch| CARDS.contains(ch)) {
+            return Err(ParseError::new(line, cards, format!("five cards out of `{}`", CARDS)));
+        }
 
-        Some(Hand { cards, bid })
+        Ok(Hand { cards: cards.chars().collect(), bid: parse_token(line, bid, "a bid")? })
     }
 
     fn hand_type(&self, joker: Option<char>) -> HandType {
@@ -68,16 +74,30 @@ impl Hand {
     }
 }
 
-fn main() {
-    let stdin = io::stdin().lock();
-    let lines = stdin.lines().filter_map(Result::ok).collect::<Vec<_>>();
-    let mut hands = lines.iter().filter_map(|line: &String| Hand::parse(line)).collect::<Vec<_>>();
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Hand>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Hand::parse)
+    }
+
+    fn part1(hands: &Self::Input) -> Result<usize, SolveError> {
+        let mut hands = hands.iter().collect::<Vec<_>>();
 
-    hands.sort_by_key(|hand| hand.key(CARDS, None));
-    println!("{}", hands.iter().rev().enumerate().map(|(i, hand)| hand.bid * (i + 1)).sum::<usize>());
+        hands.sort_by_key(|hand| hand.key(CARDS, None));
+        Ok(hands.iter().rev().enumerate().map(|(i, hand)| hand.bid * (i + 1)).sum::<usize>())
+    }
+
+    fn part2(hands: &Self::Input) -> Result<usize, SolveError> {
+        let mut hands = hands.iter().collect::<Vec<_>>();
 
-    hands.sort_by_key(|hand| hand.key(CARDS_WITH_JOKER, Some('J')));
-    println!("{}", hands.iter().rev().enumerate().map(|(i, hand)| hand.bid * (i + 1)).sum::<usize>());
+        hands.sort_by_key(|hand| hand.key(CARDS_WITH_JOKER, Some('J')));
+        Ok(hands.iter().rev().enumerate().map(|(i, hand)| hand.bid * (i + 1)).sum::<usize>())
+    }
 }
 
 #[cfg(test)]
@@ -94,7 +114,7 @@ mod tests {
 
     #[test]
     fn _01() {
-        let mut hands = LINES.iter().filter_map(|line| Hand::parse(line)).collect::<Vec<_>>();
+        let mut hands = LINES.iter().map(|line| Hand::parse(line).unwrap()).collect::<Vec<_>>();
         hands.sort_by_key(|hand: &Hand| hand.key(CARDS, None));
 
         assert_eq!(Hand::parse("AAAAA 0").unwrap().hand_type(None), HandType::Five);
@@ -116,7 +136,7 @@ mod tests {
 
     #[test]
     fn _02() {
-        let mut hands = LINES.iter().filter_map(|line| Hand::parse(line)).collect::<Vec<_>>();
+        let mut hands = LINES.iter().map(|line| Hand::parse(line).unwrap()).collect::<Vec<_>>();
         hands.sort_by_key(|hand: &Hand| hand.key(CARDS_WITH_JOKER, Some('J')));
 
         assert_eq!(
@@ -131,4 +151,11 @@ mod tests {
         );
         assert_eq!(hands.iter().rev().enumerate().map(|(i, hand)| hand.bid * (i + 1)).sum::<usize>(), 5905);
     }
+
+    #[test]
+    fn parse_error() {
+        let err = Day07::parse("32T3K 765\nT55X5 684").err().unwrap();
+
+        assert_eq!((err.line(), err.column(), err.text()), (2, 1, "T55X5"));
+    }
 }
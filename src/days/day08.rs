@@ -0,0 +1,276 @@
+use std::collections::HashMap;
+
+use crate::{prelude::*, Solution};
+
+struct Node {
+    name: String,
+    left: String,
+    right: String,
+}
+
+impl Node {
+    fn parse(line: &str) -> Result<Self, ParseError> {
+        let expected = "`<name> = (<left>, <right>)`";
+        let (name, rest) = line.split_once(" = ").ok_or_else(|| ParseError::new(line, line, expected))?;
+        let (left, right) = rest.strip_prefix('(')
+            .and_then(|rest| rest.strip_suffix(')'))
+            .and_then(|rest| rest.split_once(", "))
+            .ok_or_else(|| ParseError::new(line, rest, expected))?;
+
+        Ok(Self { name: name.to_string(), left: left.to_string(), right: right.to_string() })
+    }
+}
+
+struct Network {
+    nodes: HashMap<String, Node>,
+}
+
+impl Network {
+    fn parse(lines: impl Iterator<Item=(usize, String)>) -> Result<Network, ParseError> {
+        let nodes = lines
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(i, line)| Node::parse(&line).map_err(|err| err.at_line(i + 1)))
+            .map(|node| node.map(|node| (node.name.clone(), node)))
+            .collect::<Result<HashMap<_, _>, _>>()?;
+
+        Ok(Self { nodes })
+    }
+
+    fn nodes(&self) -> impl Iterator<Item=&str> {
+        self.nodes.keys().map(String::as_str)
+    }
+
+    fn follow(&self, node: &str, direction: char) -> Option<&str> {
+        let node = self.nodes.get(node)?;
+        let next_node = match direction {
+            'L' => &node.left,
+            'R' => &node.right,
+            _ => unreachable!(),
+        };
+
+        Some(next_node)
+    }
+}
+
+pub struct Puzzle {
+    directions: Vec<char>,
+    network: Network,
+}
+
+impl Puzzle {
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Puzzle, ParseError> {
+        let mut lines = lines.enumerate();
+        let (_, first) = lines.next().ok_or_else(|| ParseError::end_of_input("a line of `L` and `R` directions"))?;
+
+        if let Some(other) = first.find(|ch| ch != 'L' && ch != 'R') {
+            return Err(ParseError::new(&first, &first[other..], "a line of `L` and `R` directions"));
+        } else if first.is_empty() {
+            return Err(ParseError::new(&first, &first, "a line of `L` and `R` directions"));
+        }
+
+        let directions = first.chars().collect::<Vec<_>>();
+        let network = Network::parse(lines)?;
+
+        Ok(Self { directions, network })
+    }
+
+    /// Follows the directions from `start` until the ghost is back in a
+    /// state it was in before.
+    fn walk(&self, start: &str, end_suffix: &str) -> Result<Walk, SolveError> {
+        let mut visited_at = HashMap::new();
+        let mut at_end = vec! [];
+        let mut node = start;
+        let mut step = 0;
+
+        loop {
+            let direction_index = step % self.directions.len();
+
+            if let Some(&cycle_start) = visited_at.get(&(node, direction_index)) {
+                return Ok(Walk { at_end, cycle_start });
+            }
+
+            visited_at.insert((node, direction_index), step);
+            at_end.push(node.ends_with(end_suffix));
+            node = self.network.follow(node, self.directions[direction_index]).ok_or_else(|| SolveError::MissingReference(node.to_string()))?;
+            step += 1;
+        }
+    }
+
+    fn follow_directions(&self, start_suffix: &str, end_suffix: &str) -> Result<u64, SolveError> {
+        let walks = self.network.nodes()
+            .filter(|node| node.ends_with(start_suffix))
+            .map(|node| self.walk(node, end_suffix))
+            .collect::<Result<Vec<_>, _>>()?;
+        let settled = walks.iter().map(|walk| walk.cycle_start).max().unwrap_or(0);
+
+        if let Some(step) = (0..settled).find(|&step| walks.iter().all(|walk| walk.is_at_end(step))) {
+            return Ok(step as u64);
+        }
+
+        // once every ghost is in its cycle, try each combination of the end
+        // nodes they pass
+        let mut solutions = walks.iter().fold(vec! [(0, 1)], |solutions, walk| {
+            solutions.iter()
+                .flat_map(|&solution| walk.cycle_ends().filter_map(move |end| crt([solution, end])))
+                .collect()
+        });
+
+        solutions.sort_unstable();
+        solutions.dedup();
+        solutions.into_iter()
+            .map(|(remainder, modulus)| remainder + (settled as u64).saturating_sub(remainder).div_ceil(modulus) * modulus)
+            .min()
+            .ok_or_else(|| SolveError::UnreachableTarget("a step where every ghost is at an end node".to_string()))
+    }
+}
+
+/// Whether a ghost is at an end node on each step until its state repeats,
+/// which it then does every `at_end.len() - cycle_start` steps.
+struct Walk {
+    at_end: Vec<bool>,
+    cycle_start: usize,
+}
+
+impl Walk {
+    fn cycle_len(&self) -> usize {
+        self.at_end.len() - self.cycle_start
+    }
+
+    fn is_at_end(&self, step: usize) -> bool {
+        if step < self.at_end.len() {
+            self.at_end[step]
+        } else {
+            self.at_end[self.cycle_start + (step - self.cycle_start) % self.cycle_len()]
+        }
+    }
+
+    /// The steps of the cycle that are at an end node, as congruences.
+    fn cycle_ends(&self) -> impl Iterator<Item=(u64, u64)> + '_ {
+        (self.cycle_start..self.at_end.len())
+            .filter(|&step| self.at_end[step])
+            .map(|step| (step as u64, self.cycle_len() as u64))
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Puzzle;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Puzzle::parse(input.lines().map(str::to_string))
+    }
+
+    fn part1(puzzle: &Self::Input) -> Result<u64, SolveError> {
+        puzzle.follow_directions("AAA", "ZZZ")
+    }
+
+    fn part2(puzzle: &Self::Input) -> Result<u64, SolveError> {
+        puzzle.follow_directions("A", "Z")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINES: [&str; 9] = [
+        "RL",
+        "",
+        "AAA = (BBB, CCC)",
+        "BBB = (DDD, EEE)",
+        "CCC = (ZZZ, GGG)",
+        "DDD = (DDD, DDD)",
+        "EEE = (EEE, EEE)",
+        "GGG = (GGG, GGG)",
+        "ZZZ = (ZZZ, ZZZ)",
+    ];
+
+    const LINES_2: [&str; 5] = [
+        "LLR",
+        "",
+        "AAA = (BBB, BBB)",
+        "BBB = (AAA, ZZZ)",
+        "ZZZ = (ZZZ, ZZZ)",
+    ];
+
+    const LINES_3: [&str; 10] = [
+        "LR",
+        "",
+        "11A = (11B, XXX)",
+        "11B = (XXX, 11Z)",
+        "11Z = (11B, XXX)",
+        "22A = (22B, XXX)",
+        "22B = (22C, 22C)",
+        "22C = (22Z, 22Z)",
+        "22Z = (22B, 22B)",
+        "XXX = (XXX, XXX)",
+    ];
+
+    #[test]
+    fn _01() {
+        assert_eq!(
+            Puzzle::parse(LINES.iter().map(|line| line.to_string())).unwrap().follow_directions("AAA", "ZZZ"),
+            Ok(2)
+        );
+        assert_eq!(
+            Puzzle::parse(LINES_2.iter().map(|line| line.to_string())).unwrap().follow_directions("AAA", "ZZZ"),
+            Ok(6)
+        );
+    }
+
+    #[test]
+    fn _02() {
+        assert_eq!(
+            Puzzle::parse(LINES_3.iter().map(|line| line.to_string())).unwrap().follow_directions("A", "Z"),
+            Ok(6)
+        );
+    }
+
+    #[test]
+    fn offset_cycles() {
+        // `11A` reaches `11Z` after 1 step and then every 3, and `22A`
+        // reaches `22Z` after 2 steps and then every 2
+        let puzzle = Day08::parse("L\n\n11A = (11Z, XXX)\n11Z = (11B, XXX)\n11B = (11C, XXX)\n11C = (11Z, XXX)\n22A = (22B, XXX)\n22B = (22Z, XXX)\n22Z = (22C, XXX)\n22C = (22Z, XXX)").unwrap();
+
+        assert_eq!(puzzle.follow_directions("A", "Z"), Ok(4));
+
+        // here `11Z` is only reached on odd steps and `22Z` on even ones
+        let puzzle = Day08::parse("L\n\n11A = (11Z, XXX)\n11Z = (11B, XXX)\n11B = (11Z, XXX)\n22A = (22B, XXX)\n22B = (22Z, XXX)\n22Z = (22B, XXX)").unwrap();
+
+        assert_eq!(puzzle.follow_directions("A", "Z"), Err(SolveError::UnreachableTarget("a step where every ghost is at an end node".to_string())));
+    }
+
+    #[test]
+    fn several_ends() {
+        // `11A` passes `11Z` and `12Z` every 6 steps, `22A` reaches `22Z`
+        // every 3 steps from step 3, so only `12Z` lines up with it
+        let puzzle = Day08::parse("L\n\n11A = (11Z, XXX)\n11Z = (11B, XXX)\n11B = (12Z, XXX)\n12Z = (11C, XXX)\n11C = (11D, XXX)\n11D = (11A, XXX)\n22A = (22B, XXX)\n22B = (22C, XXX)\n22C = (22Z, XXX)\n22Z = (22D, XXX)\n22D = (22C, XXX)").unwrap();
+
+        assert_eq!(puzzle.follow_directions("A", "Z"), Ok(3));
+    }
+
+    #[test]
+    fn no_end() {
+        let puzzle = Day08::parse("LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)").unwrap();
+
+        assert_eq!(Day08::part1(&puzzle), Err(SolveError::UnreachableTarget("a step where every ghost is at an end node".to_string())));
+    }
+
+    #[test]
+    fn missing_node() {
+        let puzzle = Day08::parse("L\n\nAAA = (BBB, BBB)").unwrap();
+
+        assert_eq!(Day08::part1(&puzzle), Err(SolveError::MissingReference("BBB".to_string())));
+    }
+
+    #[test]
+    fn parse_error() {
+        let err = Day08::parse("LR\n\nAAA = (BBB, CCC)\nBBB = BBB, BBB").err().unwrap();
+
+        assert_eq!((err.line(), err.column(), err.text()), (4, 7, "BBB, BBB"));
+        assert_eq!(Day08::parse("\n\nAAA = (AAA, AAA)").err().map(|err| err.line()), Some(1));
+    }
+}
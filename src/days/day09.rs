@@ -1,16 +1,16 @@
-use std::io::{self, BufRead};
+use crate::{prelude::*, Solution};
 
-struct ReportHistory {
+pub struct ReportHistory {
     numbers: Vec<i64>,
 }
 
 impl ReportHistory {
-    fn parse(line: &str) -> ReportHistory {
+    fn parse(line: &str) -> Result<ReportHistory, ParseError> {
         let numbers = line.split_whitespace()
-            .filter_map(|word| word.parse::<i64>().ok())
-            .collect::<Vec<_>>();
+            .map(|word| parse_token::<i64>(line, word, "a number"))
+            .collect::<Result<Vec<_>, _>>()?;
 
-        Self { numbers }
+        Ok(Self { numbers })
     }
 
     fn rev(&self) -> Self {
@@ -38,12 +38,24 @@ impl ReportHistory {
     }
 }
 
-fn main() {
-    let stdin = io::stdin();
-    let lines = stdin.lock().lines().filter_map(Result::ok).collect::<Vec<_>>();
+pub struct Day09;
 
-    println!("{}", lines.iter().map(|line| ReportHistory::parse(line).next_number()).sum::<i64>());
-    println!("{}", lines.iter().map(|line| ReportHistory::parse(line).rev().next_number()).sum::<i64>());
+impl Solution for Day09 {
+    type Input = Vec<ReportHistory>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, ReportHistory::parse)
+    }
+
+    fn part1(histories: &Self::Input) -> Result<i64, SolveError> {
+        Ok(histories.iter().map(|history| history.next_number()).sum::<i64>())
+    }
+
+    fn part2(histories: &Self::Input) -> Result<i64, SolveError> {
+        Ok(histories.iter().map(|history| history.rev().next_number()).sum::<i64>())
+    }
 }
 
 #[cfg(test)]
@@ -59,7 +71,7 @@ mod tests {
     #[test]
     fn _01() {
         assert_eq!(
-            LINES.iter().map(|line| ReportHistory::parse(line).next_number()).collect::<Vec<_>>(),
+            LINES.iter().map(|line| ReportHistory::parse(line).unwrap().next_number()).collect::<Vec<_>>(),
             vec! [18, 28, 68]
         );
     }
@@ -67,7 +79,7 @@ mod tests {
     #[test]
     fn _02() {
         assert_eq!(
-            LINES.iter().map(|line| ReportHistory::parse(line).rev().next_number()).collect::<Vec<_>>(),
+            LINES.iter().map(|line| ReportHistory::parse(line).unwrap().rev().next_number()).collect::<Vec<_>>(),
             vec! [-3, 0, 5]
         );
     }
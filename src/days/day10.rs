@@ -0,0 +1,299 @@
+use crate::{prelude::*, Solution};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Pipe {
+    Vertical,
+    Horizontal,
+    NorthEast,
+    NorthWest,
+    SouthWest,
+    SouthEast,
+    Ground,
+    Start,
+}
+
+impl Pipe {
+    fn parse(ch: char) -> Option<Self> {
+        match ch {
+            '|' => Some(Self::Vertical),
+            '-' => Some(Self::Horizontal),
+            'L' => Some(Self::NorthEast),
+            'J' => Some(Self::NorthWest),
+            '7' => Some(Self::SouthWest),
+            'F' => Some(Self::SouthEast),
+            '.' => Some(Self::Ground),
+            'S' => Some(Self::Start),
+            _ => None,
+        }
+    }
+
+    /// The directions this pipe connects to.
+    fn connections(self) -> &'static [Direction] {
+        match self {
+            Self::Vertical => &[Direction::North, Direction::South],
+            Self::Horizontal => &[Direction::East, Direction::West],
+            Self::NorthEast => &[Direction::North, Direction::East],
+            Self::NorthWest => &[Direction::North, Direction::West],
+            Self::SouthWest => &[Direction::South, Direction::West],
+            Self::SouthEast => &[Direction::South, Direction::East],
+            Self::Ground => &[],
+            Self::Start => &[Direction::North, Direction::South, Direction::East, Direction::West],
+        }
+    }
+
+    fn connects(self, direction: Direction) -> bool {
+        self.connections().contains(&direction)
+    }
+}
+
+#[derive(Clone)]
+pub struct PipeGrid {
+    array: Grid<Pipe>,
+    is_padding: Grid<bool>,
+}
+
+impl PipeGrid {
+    fn parse<'a>(lines: impl IntoIterator<Item=&'a str>) -> Result<Self, ParseError> {
+        let array = Grid::parse(lines, Pipe::parse, "one of `|-LJ7F.S`")?;
+
+        if array.rows() == 0 {
+            return Err(ParseError::end_of_input("a grid of pipes"));
+        } else if array.position(|&pipe| pipe == Pipe::Start).is_none() {
+            return Err(ParseError::end_of_input("a starting point `S`").at_line(array.rows()));
+        }
+
+        let is_padding = Grid::new(array.rows(), array.cols(), false);
+
+        Ok(Self { array, is_padding })
+    }
+
+    fn padded(&self) -> Self {
+        let mut array = Grid::new(self.array.rows() * 2, self.array.cols() * 2, Pipe::Ground);
+        let mut is_padding = Grid::new(array.rows(), array.cols(), false);
+
+        for (point, &pipe) in self.array.iter() {
+            let at = Point2::new(point.x * 2, point.y * 2);
+            let (east, south, south_east) = (at + Vec2::new(1, 0), at + Vec2::new(0, 1), at + Vec2::new(1, 1));
+
+            is_padding[at] = self.is_padding[point];
+            is_padding[east] = true;
+            is_padding[south] = true;
+            is_padding[south_east] = true;
+
+            // the start is kept connected in every direction, and other
+            // pipes are stretched towards the south and east
+            array[at] = pipe;
+            array[south] = match pipe {
+                Pipe::Start => Pipe::Start,
+                _ if pipe.connects(Direction::South) => Pipe::Vertical,
+                _ => Pipe::Ground,
+            };
+            array[east] = match pipe {
+                Pipe::Start => Pipe::Start,
+                _ if pipe.connects(Direction::East) => Pipe::Horizontal,
+                _ => Pipe::Ground,
+            };
+        }
+
+        Self { array, is_padding }
+    }
+
+    fn starting_point(&self) -> Point2<i32> {
+        self.array.position(|&pipe| pipe == Pipe::Start).unwrap()
+    }
+
+    fn neighbours(&self, point: Point2<i32>) -> impl Iterator<Item=Point2<i32>> + '_ {
+        self.array[point].connections().iter()
+            .map(move |&direction| (point + direction.delta(), direction))
+            .filter(|&(at, direction)| self.array.get(at).is_some_and(|pipe| pipe.connects(direction.opposite())))
+            .map(|(at, _)| at)
+    }
+
+    fn traverse_pipe(&self) -> Grid<usize> {
+        let starting_point = self.starting_point();
+        let mut remaining = vec! [starting_point];
+        let mut distance_to = Grid::new(self.array.rows(), self.array.cols(), usize::MAX);
+        distance_to[starting_point] = 0;
+
+        while let Some(point) = remaining.pop() {
+            let distance = distance_to[point] + 1;
+
+            remaining.extend(self.neighbours(point).filter(|&neighbour| {
+                if distance < distance_to[neighbour] {
+                    distance_to[neighbour] = distance;
+                    true
+                } else {
+                    false
+                }
+            }));
+        }
+
+        distance_to
+    }
+
+    fn is_enclosed(&self, starting_point: Point2<i32>, is_pipe: &Grid<bool>) -> (bool, Grid<bool>) {
+        let mut remaining = vec! [starting_point];
+        let mut visited = Grid::new(self.array.rows(), self.array.cols(), false);
+        visited[starting_point] = true;
+
+        while let Some(point) = remaining.pop() {
+            if self.array.neighbours4(point).count() < 4 {
+                return (false, visited);
+            }
+
+            for (neighbour, _) in self.array.neighbours4(point) {
+                if !visited[neighbour] && !is_pipe[neighbour] {
+                    remaining.push(neighbour);
+                }
+
+                visited[neighbour] = true;
+            }
+        }
+
+        (true, visited)
+    }
+
+    fn enclosed_area(&self) -> usize {
+        let is_pipe = self.traverse_pipe().map(|&distance| distance != usize::MAX);
+        let mut enclosed = Grid::new(self.array.rows(), self.array.cols(), None);
+        let mut count = 0;
+
+        for point in self.array.points() {
+            if is_pipe[point] || self.is_padding[point] {
+                // pass
+            } else if let Some(is_enclosed) = enclosed[point] {
+                if is_enclosed {
+                    count += 1;
+                }
+            } else  {
+                let (is_enclosed, visited) = self.is_enclosed(point, &is_pipe);
+
+                count += if is_enclosed { 1 } else { 0 };
+                for (visited_point, _) in visited.iter().filter(|(_, &is_visited)| is_visited) {
+                    enclosed[visited_point] = Some(is_enclosed);
+                }
+            }
+        }
+
+        count
+    }
+
+    fn max_distance(&self) -> usize {
+        let distance_to = self.traverse_pipe();
+
+        distance_to.iter()
+            .map(|(_, &distance)| distance)
+            .filter(|&distance| distance != usize::MAX)
+            .max().unwrap_or(0)
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = PipeGrid;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        PipeGrid::parse(input.lines())
+    }
+
+    fn part1(pipe_grid: &Self::Input) -> Result<usize, SolveError> {
+        Ok(pipe_grid.max_distance())
+    }
+
+    fn part2(pipe_grid: &Self::Input) -> Result<usize, SolveError> {
+        Ok(pipe_grid.padded().enclosed_area())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINES: [&str; 5] = [
+        "..F7.",
+        ".FJ|.",
+        "SJ.L7",
+        "|F--J",
+        "LJ...",
+    ];
+
+    const SQUEEZE_LINES: [&str; 9] = [
+        "..........",
+        ".S------7.",
+        ".|F----7|.",
+        ".||....||.",
+        ".||....||.",
+        ".|L-7F-J|.",
+        ".|..||..|.",
+        ".L--JL--J.",
+        "..........",
+    ];
+
+    const LARGE_LINES: [&str; 10] = [
+        ".F----7F7F7F7F-7....",
+        ".|F--7||||||||FJ....",
+        ".||.FJ||||||||L7....",
+        "FJL7L7LJLJ||LJ.L-7..",
+        "L--J.L7...LJS7F-7L7.",
+        "....F-J..F7FJ|L7L7L7",
+        "....L7.F7||L7|.L7L7|",
+        ".....|FJLJ|FJ|F7|.LJ",
+        "....FJL-7.||.||||...",
+        "....L---J.LJ.LJLJ...",
+    ];
+
+    const EXTRA_TILES_LINES: [&str; 10] = [
+        "FF7FSF7F7F7F7F7F---7",
+        "L|LJ||||||||||||F--J",
+        "FL-7LJLJ||||||LJL-77",
+        "F--JF--7||LJLJ7F7FJ-",
+        "L---JF-JLJ.||-FJLJJ7",
+        "|F|F-JF---7F7-L7L|7|",
+        "|FFJF7L7F-JF7|JL---7",
+        "7-L-JL7||F7|L7F-7F7|",
+        "L.L7LFJ|||||FJL7||LJ",
+        "L7JLJL-JLJLJL--JLJ.L",
+    ];
+
+    #[test]
+    fn _01() {
+        let pipe_grid = PipeGrid::parse(LINES).unwrap();
+
+        assert_eq!(pipe_grid.max_distance(), 8);
+    }
+
+    #[test]
+    fn _02() {
+        let pipe_grid = PipeGrid::parse(LARGE_LINES).unwrap();
+
+        assert_eq!(pipe_grid.padded().enclosed_area(), 8);
+    }
+
+    #[test]
+    fn _02_squeeze() {
+        let pipe_grid = PipeGrid::parse(SQUEEZE_LINES).unwrap();
+
+        assert_eq!(pipe_grid.padded().enclosed_area(), 4);
+    }
+
+    #[test]
+    fn _02_extra_tiles() {
+        let pipe_grid = PipeGrid::parse(EXTRA_TILES_LINES).unwrap();
+
+        assert_eq!(pipe_grid.padded().enclosed_area(), 10);
+    }
+
+    #[test]
+    fn lone_start() {
+        assert_eq!(Day10::part1(&Day10::parse("S").unwrap()), Ok(0));
+    }
+
+    #[test]
+    fn parse_error() {
+        assert_eq!(Day10::parse("-L|F7\n7S-7|\nL|7|").err().map(|err| (err.line(), err.column())), Some((3, 1)));
+        assert_eq!(Day10::parse("-L|F7\n7S-7|\nL|x||").err().map(|err| (err.line(), err.column())), Some((3, 3)));
+    }
+}
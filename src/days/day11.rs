@@ -0,0 +1,113 @@
+use crate::{prelude::*, Solution};
+
+type Galaxy = Point2<usize>;
+
+pub struct Image {
+    galaxies: Vec<Galaxy>
+}
+
+impl Image {
+    fn parse(lines: impl Iterator<Item=String>) -> Result<Self, ParseError> {
+        let lines = lines.collect::<Vec<_>>();
+
+        for (i, line) in lines.iter().enumerate() {
+            check_chars(line, ".#").map_err(|err| err.at_line(i + 1))?;
+        }
+
+        Ok(Self {
+            galaxies: lines.iter().enumerate()
+                .flat_map(|(row, line)| line.chars().enumerate().map(move |(col, ch)| (row, col, ch)).collect::<Vec<_>>())
+                .filter(|(_, _, ch)| *ch == '#')
+                .map(|(row, col, _)| Galaxy::new(col, row))
+                .collect(),
+        })
+    }
+
+    fn is_row_empty(&self, row: usize) -> bool {
+        self.galaxies.iter().all(|galaxy| galaxy.y != row)
+    }
+
+    fn is_col_empty(&self, col: usize) -> bool {
+        self.galaxies.iter().all(|galaxy| galaxy.x != col)
+    }
+
+    fn padded(&self, multiplier: usize) -> Self {
+        Self {
+            galaxies: self.galaxies.iter().map(|galaxy| {
+                let num_row_voids = (0..galaxy.y).filter(|&row| self.is_row_empty(row)).count();
+                let num_col_voids = (0..galaxy.x).filter(|&col| self.is_col_empty(col)).count();
+
+                Galaxy::new(
+                    galaxy.x + (multiplier - 1) * num_col_voids,
+                    galaxy.y + (multiplier - 1) * num_row_voids,
+                )
+            }).collect()
+        }
+    }
+
+    fn pairs(&self) -> impl Iterator<Item=(&Galaxy, &Galaxy)> {
+        self.galaxies.iter().enumerate().flat_map(move |(i, galaxy)| {
+            self.galaxies.iter().skip(i + 1).map(move |other| (galaxy, other))
+        })
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Image;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Image::parse(input.lines().map(str::to_string))
+    }
+
+    fn part1(image: &Self::Input) -> Result<usize, SolveError> {
+        Ok(image.padded(2).pairs().map(|(a, b)| a.manhattan_distance(b)).sum::<usize>())
+    }
+
+    fn part2(image: &Self::Input) -> Result<usize, SolveError> {
+        Ok(image.padded(1000000).pairs().map(|(a, b)| a.manhattan_distance(b)).sum::<usize>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINES: [&str; 10] = [
+        "...#......",
+        ".......#..",
+        "#.........",
+        "..........",
+        "......#...",
+        ".#........",
+        ".........#",
+        "..........",
+        ".......#..",
+        "#...#.....",
+    ];
+
+    #[test]
+    fn _01() {
+        let image = Image::parse(LINES.iter().map(|line| line.to_string())).unwrap().padded(2);
+
+        assert_eq!(image.pairs().count(), 36);
+        assert_eq!(image.pairs().map(|(a, b)| a.manhattan_distance(b)).sum::<usize>(), 374);
+    }
+
+    #[test]
+    fn _02_10() {
+        let image = Image::parse(LINES.iter().map(|line| line.to_string())).unwrap().padded(10);
+
+        assert_eq!(image.pairs().map(|(a, b)| a.manhattan_distance(b)).sum::<usize>(), 1030);
+    }
+
+    #[test]
+    fn _02_100() {
+        let image = Image::parse(LINES.iter().map(|line| line.to_string())).unwrap().padded(100);
+
+        assert_eq!(image.pairs().map(|(a, b)| a.manhattan_distance(b)).sum::<usize>(), 8410);
+    }
+}
@@ -1,17 +1,22 @@
-use std::{io::{self, BufRead}, collections::HashMap};
+use std::collections::HashMap;
 
-struct Springs {
+use crate::{prelude::*, Solution};
+
+pub struct Springs {
     conditions: Vec<char>,
     sequences: Vec<usize>,
 }
 
 impl Springs {
-    fn parse(line: &str) -> Option<Self> {
-        let mut parts = line.split_whitespace();
-        let conditions = parts.next()?.chars().collect::<Vec<_>>();
-        let sequences = parts.next()?.split(',').map(|part| part.parse().unwrap()).collect::<Vec<_>>();
+    fn parse(line: &str) -> Result<Self, ParseError> {
+        let (conditions, sequences) = line.split_once(' ').ok_or_else(|| ParseError::new(line, line, "`<conditions> <sequences>`"))?;
+
+        check_chars(conditions, ".#?")?;
 
-        Some(Self { conditions, sequences })
+        Ok(Self {
+            conditions: conditions.chars().collect(),
+            sequences: sequences.split(',').map(|part| parse_token(line, part, "a number")).collect::<Result<Vec<_>, _>>()?,
+        })
     }
 
     fn unfold(&self) -> Self {
@@ -32,14 +37,15 @@ impl Springs {
         if let Some(result) = visited.get(&(offset, current_sequence, remaining_dots)) {
             return *result;
         } else if current_sequence == self.sequences.len() {
-            if self.conditions[offset..].iter().any(|&ch| ch == '#') {
+            if self.conditions[offset..].contains(&'#') {
                 return 0;
             } else {
                 return 1;
             }
-        } else if remaining_dots + current_sequence < self.sequences.len() - 1 {
-            return 0;
-        } else if self.conditions.len() < offset + self.sequences[current_sequence] || self.conditions.len() < offset + remaining_dots {
+        } else if remaining_dots + current_sequence < self.sequences.len() - 1
+            || self.conditions.len() < offset + self.sequences[current_sequence]
+            || self.conditions.len() < offset + remaining_dots
+        {
             return 0;
         }
 
@@ -48,7 +54,7 @@ impl Springs {
 
         if self.conditions[offset] == '#' {
             // next `len` characters must be '#' or '?'
-            if self.conditions[offset..end].iter().any(|&ch| ch == '.') {
+            if self.conditions[offset..end].contains(&'.') {
                 return 0;
             }
 
@@ -79,7 +85,7 @@ impl Springs {
                 };
 
             // next `len` characters must be '#' or '?'
-            if self.conditions[offset..end].iter().any(|&ch| ch == '.') {
+            if self.conditions[offset..end].contains(&'.') {
                 return dot_assignments;
             }
 
@@ -103,13 +109,24 @@ impl Springs {
     }
 }
 
-fn main() {
-    let stdin = io::stdin().lock();
-    let lines = stdin.lines().map(Result::unwrap).collect::<Vec<_>>();
-    let springs = lines.into_iter().filter_map(|springs| Springs::parse(&springs)).collect::<Vec<_>>();
+pub struct Day12;
 
-    println!("{}", springs.iter().map(|springs| springs.assignments()).sum::<usize>());
-    println!("{}", springs.iter().map(|springs| springs.unfold().assignments()).sum::<usize>());
+impl Solution for Day12 {
+    type Input = Vec<Springs>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Springs::parse)
+    }
+
+    fn part1(springs: &Self::Input) -> Result<usize, SolveError> {
+        Ok(springs.iter().map(|springs| springs.assignments()).sum::<usize>())
+    }
+
+    fn part2(springs: &Self::Input) -> Result<usize, SolveError> {
+        Ok(springs.iter().map(|springs| springs.unfold().assignments()).sum::<usize>())
+    }
 }
 
 #[cfg(test)]
@@ -127,7 +144,7 @@ mod tests {
 
     #[test]
     fn _01() {
-        let springs = LINES.iter().filter_map(|line| Springs::parse(line)).collect::<Vec<_>>();
+        let springs = LINES.iter().map(|line| Springs::parse(line).unwrap()).collect::<Vec<_>>();
 
         assert_eq!(springs.iter().map(|springs| springs.assignments()).collect::<Vec<_>>(), [1, 4, 1, 1, 4, 10]);
         assert_eq!(springs.iter().map(|springs| springs.assignments()).sum::<usize>(), 21);
@@ -157,7 +174,7 @@ mod tests {
 
     #[test]
     fn _02() {
-        let springs = LINES.iter().filter_map(|line| Springs::parse(line)).collect::<Vec<_>>();
+        let springs = LINES.iter().map(|line| Springs::parse(line).unwrap()).collect::<Vec<_>>();
 
         assert_eq!(springs.iter().map(|springs| springs.unfold().assignments()).collect::<Vec<_>>(), [1, 16384, 1, 16, 2500, 506250]);
         assert_eq!(springs.iter().map(|springs| springs.unfold().assignments()).sum::<usize>(), 525152);
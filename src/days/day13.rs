@@ -0,0 +1,136 @@
+use crate::{prelude::*, Solution};
+
+pub struct Pattern {
+    grid: Grid<char>,
+}
+
+impl Pattern {
+    fn parse<'a>(lines: &mut impl Iterator<Item=(usize, &'a str)>) -> Result<Option<Self>, ParseError> {
+        let block = lines.take_while(|(_, line)| !line.is_empty()).collect::<Vec<_>>();
+        let first_line = block.first().map(|&(i, _)| i).unwrap_or(0);
+        let grid = Grid::parse_chars(block.into_iter().map(|(_, line)| line), ".#")
+            .map_err(|err| { let line = err.line(); err.at_line(first_line + line) })?;
+
+        if grid.rows() == 0 {
+            Ok(None)
+        } else {
+            Ok(Some(Self { grid }))
+        }
+    }
+
+    fn parse_all<'a>(lines: &mut impl Iterator<Item=(usize, &'a str)>) -> Result<Vec<Self>, ParseError> {
+        let mut patterns = vec! [];
+
+        while let Some(pattern) = Self::parse(lines)? {
+            patterns.push(pattern);
+        }
+
+        Ok(patterns)
+    }
+
+    fn summarize(&self, max_smudges: usize) -> usize {
+        let num_columns = Self::find_mirror(&self.grid.transpose(), max_smudges).unwrap_or(0);
+        let num_rows = Self::find_mirror(&self.grid, max_smudges).unwrap_or(0);
+
+        100 * num_rows + num_columns
+    }
+
+    fn find_mirror(grid: &Grid<char>, max_smudges: usize) -> Option<usize> {
+        (1..grid.rows())
+            .find(move |&row| Self::num_mirrored_differences(grid, row) == max_smudges)
+    }
+
+    fn num_mirrored_differences(grid: &Grid<char>, row: usize) -> usize {
+        (0..row).rev()
+            .zip(row..grid.rows())
+            .map(|(i, j)| {
+                grid.row(i).iter().zip(grid.row(j)).filter(|&(a, b)| a != b).count()
+            })
+            .sum()
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Pattern>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Pattern::parse_all(&mut input.lines().enumerate())
+    }
+
+    fn part1(patterns: &Self::Input) -> Result<usize, SolveError> {
+        Ok(patterns.iter().map(|pattern| pattern.summarize(0)).sum::<usize>())
+    }
+
+    fn part2(patterns: &Self::Input) -> Result<usize, SolveError> {
+        Ok(patterns.iter().map(|pattern| pattern.summarize(1)).sum::<usize>())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINES: [&str; 15] = [
+        "#.##..##.",
+        "..#.##.#.",
+        "##......#",
+        "##......#",
+        "..#.##.#.",
+        "..##..##.",
+        "#.#.##.#.",
+        "",
+        "#...##..#",
+        "#....#..#",
+        "..##..###",
+        "#####.##.",
+        "#####.##.",
+        "..##..###",
+        "#....#..#",
+    ];
+
+    const RIGHT_EDGE: [&str; 17] = [
+        "...##.....#..##",
+        "..#..#.....#...",
+        "##.##.##.#..#..",
+        "..#..#...##..##",
+        "..........##...",
+        "..#..#..##..#..",
+        "#......###.#...",
+        "#..##..#..#.###",
+        "........#.#....",
+        "##....##.#.##..",
+        "##.##.###.#..##",
+        "##....###.##.##",
+        "#.####.#.#..#..",
+        ".######...##...",
+        "...##..##..#.##",
+        ".##..##.#.###..",
+        ".#....#.##.##..",
+    ];
+
+    #[test]
+    fn _01() {
+        let patterns = Pattern::parse_all(&mut LINES.iter().copied().enumerate()).unwrap();
+
+        assert_eq!(patterns.len(), 2);
+        assert_eq!(patterns.iter().map(|pattern| pattern.summarize(0)).sum::<usize>(), 405);
+    }
+
+    #[test]
+    fn _01_edge() {
+        let pattern = Pattern::parse(&mut RIGHT_EDGE.iter().copied().enumerate()).unwrap().unwrap();
+
+        assert_eq!(pattern.summarize(0), 14);
+    }
+
+    #[test]
+    fn _02() {
+        let patterns = Pattern::parse_all(&mut LINES.iter().copied().enumerate()).unwrap();
+
+        assert_eq!(patterns.iter().map(|pattern| pattern.summarize(1)).sum::<usize>(), 400);
+    }
+}
@@ -0,0 +1,149 @@
+use std::{collections::HashMap, fmt::{self, Display}};
+
+use crate::{prelude::*, Solution};
+
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Platform {
+    grid: Grid<char>,
+}
+
+impl Display for Platform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.grid)
+    }
+}
+
+impl Platform {
+    fn parse<'a>(lines: impl IntoIterator<Item=&'a str>) -> Result<Self, ParseError> {
+        Ok(Self { grid: Grid::parse_chars(lines, ".#O")? })
+    }
+
+    fn tilted_north(mut grid: Grid<char>) -> Grid<char> {
+        for col in 0..grid.cols() as i32 {
+            let mut free_row = 0;
+
+            for row in 0..grid.rows() as i32 {
+                match grid[Point2::new(col, row)] {
+                    '#' => { free_row = row + 1 },
+                    'O' => {
+                        grid[Point2::new(col, row)] = '.';
+                        grid[Point2::new(col, free_row)] = 'O';
+                        free_row += 1;
+                    },
+                    _ => { /* pass */ },
+                }
+            }
+        }
+
+        grid
+    }
+
+    fn tilted(&self, direction: Direction) -> Self {
+        let num_turns = match direction {
+            Direction::North => 0,
+            Direction::West => 1,
+            Direction::South => 2,
+            Direction::East => 3,
+        };
+        let grid = (0..num_turns).fold(self.grid.clone(), |grid, _| grid.rotate_right());
+        let grid = (0..num_turns).fold(Self::tilted_north(grid), |grid, _| grid.rotate_left());
+
+        Self { grid }
+    }
+
+    fn cycle_once(&self) -> Self {
+        self.tilted(Direction::North)
+            .tilted(Direction::West)
+            .tilted(Direction::South)
+            .tilted(Direction::East)
+    }
+
+    fn cycle_loop(&self, n: usize) -> Self {
+        (0..n).fold(self.clone(), |platform, _| platform.cycle_once())
+    }
+
+    fn cycle(&self, n: usize) -> Self {
+        let mut visited = HashMap::new();
+        let mut result = self.clone();
+        let mut count = 0;
+
+        while count < n {
+            if let Some(&prev_count) = visited.get(&result) {
+                let cycle_length = count - prev_count;
+                let remaining = n - count;
+
+                return result.cycle_loop(remaining % cycle_length);
+            } else {
+                visited.insert(result.clone(), count);
+            }
+
+            result = result.cycle_once();
+            count += 1;
+        }
+
+        result
+    }
+
+    fn total_load(&self) -> usize {
+        let len = self.grid.rows() as i32;
+
+        self.grid.iter()
+            .filter_map(|(point, &ch)| if ch == 'O' { Some((len - point.y) as usize) } else { None })
+            .sum()
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Platform;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Platform::parse(input.lines())
+    }
+
+    fn part1(platform: &Self::Input) -> Result<usize, SolveError> {
+        Ok(platform.tilted(Direction::North).total_load())
+    }
+
+    fn part2(platform: &Self::Input) -> Result<usize, SolveError> {
+        Ok(platform.cycle(1000000000).total_load())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINES: [&str; 10] = [
+        "O....#....",
+        "O.OO#....#",
+        ".....##...",
+        "OO.#O....O",
+        ".O.....O#.",
+        "O.#..O.#.#",
+        "..O..#O..O",
+        ".......O..",
+        "#....###..",
+        "#OO..#....",
+    ];
+
+    #[test]
+    fn _01() {
+        let platform: Platform = Platform::parse(LINES).unwrap();
+
+        assert_eq!(platform.tilted(Direction::North).total_load(), 136, "\n{}", platform.tilted(Direction::North));
+    }
+
+    #[test]
+    fn _02() {
+        let platform: Platform = Platform::parse(LINES).unwrap();
+
+        assert_eq!(platform.cycle(1).total_load(), 87, "\n{}", platform.cycle(1));
+        assert_eq!(platform.cycle(2).total_load(), 69, "\n{}", platform.cycle(2));
+        assert_eq!(platform.cycle(3).total_load(), 69, "\n{}", platform.cycle(3));
+        assert_eq!(platform.cycle(1000000000).total_load(), 64, "\n{}", platform.cycle(1000000000));
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{prelude::*, Solution};
+
+fn hash(s: &str) -> usize {
+    s.chars().fold(0, |acc, ch| ((acc + ch as usize) * 17) % 256)
+}
+
+#[derive(Clone, Copy)]
+enum Operation {
+    Insert(u64),
+    Remove,
+}
+
+struct Step {
+    label: String,
+    operation: Operation,
+    box_number: usize,
+    hash_code: usize,
+}
+
+impl Step {
+    fn parse(line: &str, s: &str) -> Result<Self, ParseError> {
+        let hash_code = hash(s);
+
+        if let Some((label, focal_length)) = s.split_once('=') {
+            Ok(Self {
+                label: label.to_string(),
+                operation: Operation::Insert(parse_token(line, focal_length, "a focal length")?),
+                box_number: hash(label),
+                hash_code,
+            })
+        } else if let Some(label) = s.strip_suffix('-') {
+            Ok(Self {
+                label: label.to_string(),
+                operation: Operation::Remove,
+                box_number: hash(label),
+                hash_code,
+            })
+        } else {
+            Err(ParseError::new(line, s, "`<label>=<focal length>` or `<label>-`"))
+        }
+    }
+}
+
+pub struct InitSeq {
+    steps: Vec<Step>,
+}
+
+impl InitSeq {
+    fn parse(line: &str) -> Result<Self, ParseError> {
+        Ok(Self {
+            steps: line.split(',').map(|s| Step::parse(line, s)).collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+
+    fn verification_number(&self) -> usize {
+        self.steps.iter().map(|s| s.hash_code).sum()
+    }
+
+    fn focusing_power(&self) -> usize {
+        let mut buckets = vec! [Vec::<(&str, u64)>::new(); 256];
+
+        for step in &self.steps {
+            let bucket = &mut buckets[step.box_number];
+
+            match step.operation {
+                Operation::Insert(focal_length) => {
+                    if let Some(index) = bucket.iter().position(|&(label, _)| label == step.label) {
+                        bucket[index] = (&step.label, focal_length);
+                    } else {
+                        bucket.push((&step.label, focal_length));
+                    }
+                },
+                Operation::Remove => bucket.retain(|&(label, _)| label != step.label),
+            }
+        }
+
+        buckets.iter()
+            .enumerate()
+            .flat_map(|(i, bucket)| {
+                bucket.iter().enumerate().map(move |(j, &(_, focal_length))| (i + 1) * (j + 1) * (focal_length as usize))
+            })
+            .sum()
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = InitSeq;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        InitSeq::parse(input.lines().next().ok_or_else(|| ParseError::end_of_input("an initialization sequence"))?)
+    }
+
+    fn part1(init_seq: &Self::Input) -> Result<usize, SolveError> {
+        Ok(init_seq.verification_number())
+    }
+
+    fn part2(init_seq: &Self::Input) -> Result<usize, SolveError> {
+        Ok(init_seq.focusing_power())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINE: &str = "rn=1,cm-,qp=3,cm=2,qp-,pc=4,ot=9,ab=5,pc-,pc=6,ot=7";
+
+    #[test]
+    fn _01() {
+        assert_eq!(InitSeq::parse(LINE).unwrap().verification_number(), 1320);
+    }
+
+    #[test]
+    fn _02() {
+        assert_eq!(InitSeq::parse(LINE).unwrap().focusing_power(), 145);
+    }
+
+    #[test]
+    fn parse_error() {
+        let err = Day15::parse("rn=1,cm-,qp=x").err().unwrap();
+
+        assert_eq!((err.line(), err.column(), err.text()), (1, 13, "x"));
+    }
+}
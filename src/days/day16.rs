@@ -0,0 +1,108 @@
+use std::collections::{HashMap, HashSet};
+
+use rayon::prelude::*;
+
+use crate::{prelude::*, Solution};
+
+pub struct Contraption {
+    grid: Grid<char>,
+}
+
+impl Contraption {
+    fn parse<'a>(lines: impl IntoIterator<Item=&'a str>) -> Result<Self, ParseError> {
+        Ok(Self { grid: Grid::parse_chars(lines, "./\\|-")? })
+    }
+
+    fn best_energized(&self) -> usize {
+        let max = Point2::new(self.grid.cols() as i32 - 1, self.grid.rows() as i32 - 1);
+        let rows = (0..=max.y).flat_map(|y| [ (Point2::new(0, y), Direction::East), (Point2::new(max.x, y), Direction::West) ]);
+        let cols = (0..=max.x).flat_map(|x| [ (Point2::new(x, 0), Direction::South), (Point2::new(x, max.y), Direction::North) ]);
+
+        rows.chain(cols)
+            .par_bridge()
+            .map(|(point, direction)| self.energized(point, direction))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn energized(&self, starting_point: Point2<i32>, direction: Direction) -> usize {
+        let mut energized = HashMap::new();
+        let mut visited = HashSet::new();
+        let mut remaining = vec! [ (starting_point, direction) ];
+
+        while let Some((point, direction)) = remaining.pop() {
+            if !self.grid.contains(point) || visited.contains(&(point, direction)) {
+                continue;
+            }
+
+            *energized.entry(point).or_insert(0) += 1;
+            visited.insert((point, direction));
+
+            let next_directions = match self.grid[point] {
+                '/' if direction.is_horizontal() => vec! [direction.turn_left()],
+                '/' => vec! [direction.turn_right()],
+                '\\' if direction.is_horizontal() => vec! [direction.turn_right()],
+                '\\' => vec! [direction.turn_left()],
+                '-' if direction.is_vertical() => vec! [Direction::West, Direction::East],
+                '|' if direction.is_horizontal() => vec! [Direction::North, Direction::South],
+                _ => vec! [direction],
+            };
+
+            remaining.extend(next_directions.into_iter().map(|direction| (point + direction.delta(), direction)));
+        }
+
+        energized.into_values().count()
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Contraption;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Contraption::parse(input.lines())
+    }
+
+    fn part1(contraption: &Self::Input) -> Result<usize, SolveError> {
+        Ok(contraption.energized(Point2::new(0, 0), Direction::East))
+    }
+
+    fn part2(contraption: &Self::Input) -> Result<usize, SolveError> {
+        Ok(contraption.best_energized())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINES: [&str; 10] = [
+        ".|...\\....",
+        "|.-.\\.....",
+        ".....|-...",
+        "........|.",
+        "..........",
+        ".........\\",
+        "..../.\\\\..",
+        ".-.-/..|..",
+        ".|....-|.\\",
+        "..//.|....",
+    ];
+
+    #[test]
+    fn _01() {
+        let contraption = Contraption::parse(LINES.iter().copied()).unwrap();
+
+        assert_eq!(contraption.energized(Point2::new(0, 0), Direction::East), 46);
+    }
+
+    #[test]
+    fn _02() {
+        let contraption = Contraption::parse(LINES.iter().copied()).unwrap();
+
+        assert_eq!(contraption.best_energized(), 51);
+    }
+}
@@ -0,0 +1,251 @@
+use std::ops::RangeInclusive;
+
+use crate::{prelude::*, Solution};
+
+const CRUCIBLE: RangeInclusive<i32> = 1..=3;
+const ULTRA_CRUCIBLE: RangeInclusive<i32> = 4..=10;
+
+pub struct Map {
+    grid: Grid<i32>,
+}
+
+/// Where a crucible is and which way it moved last, since it has to turn
+/// before moving again.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+struct Crucible {
+    position: Point2<i32>,
+    heading: Direction,
+}
+
+/// The cheapest route from the top left block to the bottom right one, as a
+/// sequence of straight moves.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Route {
+    heat_loss: i32,
+    moves: Vec<(Direction, i32)>,
+}
+
+impl Route {
+    pub fn heat_loss(&self) -> i32 {
+        self.heat_loss
+    }
+
+    pub fn moves(&self) -> &[(Direction, i32)] {
+        &self.moves
+    }
+}
+
+fn arrow(direction: Direction) -> char {
+    match direction {
+        Direction::North => '^',
+        Direction::East => '>',
+        Direction::South => 'v',
+        Direction::West => '<',
+    }
+}
+
+impl Map {
+    fn parse<'a>(lines: impl IntoIterator<Item=&'a str>) -> Result<Self, ParseError> {
+        Ok(Self { grid: Grid::parse(lines, |ch| ch.to_digit(10).map(|digit| digit as i32), "a digit")? })
+    }
+
+    fn target(&self) -> Point2<i32> {
+        Point2::new(self.grid.cols() as i32 - 1, self.grid.rows() as i32 - 1)
+    }
+
+    /// Every state reachable by turning left or right and then moving a
+    /// `valid_distances` number of blocks in a straight line, together with
+    /// the heat lost along the way.
+    fn successors(&self, crucible: &Crucible, valid_distances: RangeInclusive<i32>) -> Vec<(Crucible, i32)> {
+        let mut successors = vec! [];
+
+        for heading in [crucible.heading.turn_left(), crucible.heading.turn_right()] {
+            let mut heat_loss = 0;
+
+            for distance in 1..=*valid_distances.end() {
+                let position = crucible.position + heading.delta() * distance;
+
+                match self.grid.get(position) {
+                    Some(&heat) => heat_loss += heat,
+                    None => break,
+                }
+
+                if valid_distances.contains(&distance) {
+                    successors.push((Crucible { position, heading }, heat_loss));
+                }
+            }
+        }
+
+        successors
+    }
+
+    fn min_heat_route(&self, valid_distances: RangeInclusive<i32>) -> Result<Route, SolveError> {
+        let start = Point2::new(0, 0);
+        let target = self.target();
+        let min_heat = self.grid.iter().map(|(_, &heat)| heat).min().unwrap_or(0);
+        let found = astar(
+            [Direction::North, Direction::West].map(|heading| Crucible { position: start, heading }),
+            |crucible| self.successors(crucible, valid_distances.clone()),
+            |crucible| min_heat * crucible.position.manhattan_distance(&target),
+            |crucible| crucible.position == target
+        ).ok_or_else(|| SolveError::UnreachableTarget("machine parts factory".to_string()))?;
+        let moves = found.path()
+            .windows(2)
+            .map(|pair| (pair[1].heading, pair[0].position.manhattan_distance(&pair[1].position)))
+            .collect();
+
+        Ok(Route { heat_loss: found.cost(), moves })
+    }
+
+    fn min_heat(&self, valid_distances: RangeInclusive<i32>) -> Result<i32, SolveError> {
+        self.min_heat_route(valid_distances).map(|route| route.heat_loss)
+    }
+
+    /// Draws the map with every block the `route` passes through replaced by
+    /// the direction it was entered in, like the puzzle text does.
+    fn render(&self, route: &Route) -> Grid<char> {
+        let mut drawing = self.grid.map(|&heat| char::from_digit(heat as u32, 10).unwrap_or('?'));
+        let mut position = Point2::new(0, 0);
+
+        for &(direction, distance) in &route.moves {
+            for _ in 0..distance {
+                position += direction.delta();
+                drawing[position] = arrow(direction);
+            }
+        }
+
+        drawing
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Map;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Map::parse(input.lines())
+    }
+
+    fn part1(map: &Self::Input) -> Result<i32, SolveError> {
+        map.min_heat(CRUCIBLE)
+    }
+
+    fn part2(map: &Self::Input) -> Result<i32, SolveError> {
+        map.min_heat(ULTRA_CRUCIBLE)
+    }
+
+    fn render(map: &Self::Input, part: u32) -> Option<Result<String, SolveError>> {
+        let valid_distances = match part {
+            1 => CRUCIBLE,
+            2 => ULTRA_CRUCIBLE,
+            _ => return None,
+        };
+
+        Some(map.min_heat_route(valid_distances).map(|route| map.render(&route).to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINES: [&str; 13] = [
+        "2413432311323",
+        "3215453535623",
+        "3255245654254",
+        "3446585845452",
+        "4546657867536",
+        "1438598798454",
+        "4457876987766",
+        "3637877979653",
+        "4654967986887",
+        "4564679986453",
+        "1224686865563",
+        "2546548887735",
+        "4322674655533",
+    ];
+
+    const LINES_2: [&str; 5] = [
+        "111111111111",
+        "999999999991",
+        "999999999991",
+        "999999999991",
+        "999999999991",
+    ];
+
+    const LINES_ZERO: [&str; 4] = [
+        "903104",
+        "078010",
+        "009000",
+        "005004",
+    ];
+
+    #[test]
+    fn _01() {
+        let map = Map::parse(LINES.iter().copied()).unwrap();
+
+        assert_eq!(map.min_heat(1..=3), Ok(102));
+    }
+
+    #[test]
+    fn _02() {
+        let map = Map::parse(LINES.iter().copied()).unwrap();
+
+        assert_eq!(map.min_heat(4..=10), Ok(94));
+    }
+
+    #[test]
+    fn _02_another_example() {
+        let map = Map::parse(LINES_2.iter().copied()).unwrap();
+
+        assert_eq!(map.min_heat(4..=10), Ok(71));
+    }
+
+    #[test]
+    fn route() {
+        let map = Map::parse(LINES.iter().copied()).unwrap();
+        let route = map.min_heat_route(1..=3).unwrap();
+        let drawing = map.render(&route);
+
+        assert_eq!(route.heat_loss(), 102);
+        assert!(route.moves().iter().all(|&(_, distance)| (1..=3).contains(&distance)));
+        assert!(route.moves().windows(2).all(|pair| pair[0].0.turn_left() == pair[1].0 || pair[0].0.turn_right() == pair[1].0));
+        assert_eq!(drawing[Point2::new(0, 0)], '2');
+        assert!(">v".contains(drawing[Point2::new(12, 12)]));
+        assert_eq!(
+            drawing.iter().filter(|(_, ch)| "^>v<".contains(**ch)).map(|(point, _)| map.grid[point]).sum::<i32>(),
+            102
+        );
+    }
+
+    #[test]
+    fn route_zero_heat() {
+        let map = Map::parse(LINES_ZERO.iter().copied()).unwrap();
+        let route = map.min_heat_route(1..=3).unwrap();
+        let drawing = map.render(&route);
+
+        assert_eq!(route.heat_loss(), 8);
+        assert_eq!(
+            drawing.iter().filter(|(_, ch)| "^>v<".contains(**ch)).map(|(point, _)| map.grid[point]).sum::<i32>(),
+            8
+        );
+        assert_eq!(Day17::render(&map, 1).unwrap().unwrap(), drawing.to_string());
+    }
+
+    #[test]
+    fn zero_heat() {
+        let map = Map::parse(LINES_ZERO.iter().copied()).unwrap();
+
+        assert_eq!(map.min_heat(1..=3), Ok(8));
+    }
+
+    #[test]
+    fn unreachable() {
+        let map = Map::parse(["111"].iter().copied()).unwrap();
+
+        assert_eq!(map.min_heat(4..=10), Err(SolveError::UnreachableTarget("machine parts factory".to_string())));
+    }
+}
@@ -0,0 +1,236 @@
+use std::ops::RangeInclusive;
+
+use rayon::iter::{ParallelBridge, ParallelIterator};
+
+use crate::{prelude::*, Solution};
+
+fn direction_from_str(s: &str) -> Option<Direction> {
+    match s {
+        "U" => Some(Direction::North),
+        "D" => Some(Direction::South),
+        "L" => Some(Direction::West),
+        "R" => Some(Direction::East),
+        _ => None,
+    }
+}
+
+fn direction_from_hex(s: &str) -> Option<Direction> {
+    match s {
+        "0" => Some(Direction::East),
+        "1" => Some(Direction::South),
+        "2" => Some(Direction::West),
+        "3" => Some(Direction::North),
+        _ => None,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DigPlan {
+    direction: Direction,
+    distance: i32,
+    color: String,
+}
+
+impl DigPlan {
+    fn parse(s: &str) -> Result<Self, ParseError> {
+        let mut parts = s.split_whitespace();
+        let mut next_part = |expected: &str| parts.next().ok_or_else(|| ParseError::new(s, &s[s.len()..], expected));
+        let direction = next_part("a direction")?;
+        let direction = direction_from_str(direction).ok_or_else(|| ParseError::new(s, direction, "one of `U`, `D`, `L` or `R`"))?;
+        let distance = next_part("a distance")?;
+        let distance = parse_token(s, distance, "a distance")?;
+        let color = next_part("a color")?;
+        let color = color.strip_prefix("(#")
+            .and_then(|color| color.strip_suffix(')'))
+            .filter(|color| color.len() == 6 && color.chars().all(|ch| ch.is_ascii_hexdigit()) && direction_from_hex(&color[5..]).is_some())
+            .ok_or_else(|| ParseError::new(s, color, "`(#<distance><direction>)` in hex"))?
+            .to_string();
+
+        Ok(Self { direction, distance, color })
+    }
+
+    fn to_hex_plan(&self) -> Option<Self> {
+        let distance = i32::from_str_radix(&self.color[0..5], 16).ok()?;
+        let direction = direction_from_hex(&self.color[5..])?;
+        let color = self.color.clone();
+
+        Some(Self { direction, distance, color })
+    }
+
+    fn parse_all<'a>(lines: impl Iterator<Item=&'a str>) -> Result<Vec<Self>, ParseError> {
+        lines.enumerate()
+            .map(|(i, line)| Self::parse(line).map_err(|err| err.at_line(i + 1)))
+            .collect()
+    }
+
+    fn direction(&self) -> Vec2<i32> {
+        self.direction.delta()
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Line {
+    start_point: Point2<i32>,
+    end_point: Point2<i32>,
+    direction: Direction,
+}
+
+impl Line {
+    fn max(&self) -> Point2<i32> {
+        Point2::new(self.start_point.x.max(self.end_point.x), self.start_point.y.max(self.end_point.y))
+    }
+
+    fn min(&self) -> Point2<i32> {
+        Point2::new(self.start_point.x.min(self.end_point.x), self.start_point.y.min(self.end_point.y))
+    }
+}
+
+struct Trench {
+    lines: Vec<Line>,
+    min: Point2<i32>,
+    max: Point2<i32>,
+}
+
+impl Trench {
+    fn dig(plans: impl Iterator<Item=DigPlan>) -> Self {
+         let (_, mut lines) = plans
+             .fold(
+                 (Point2::new(0, 0), vec! []),
+                 move |(start_point, mut lines), plan| {
+                    let end_point = start_point + plan.direction() * plan.distance;
+
+                    lines.push(Line { start_point, end_point, direction: plan.direction });
+                    (end_point, lines)
+                 }
+             );
+        lines.sort_unstable_by_key(|line| line.min().x);
+
+        Self {
+            lines: lines.clone(),
+            min: lines.iter().fold(Point2::new(i32::MAX, i32::MAX), |acc, line| Point2::new(acc.x.min(line.min().x), acc.y.min(line.min().y))),
+            max: lines.iter().fold(Point2::new(i32::MIN, i32::MIN), |acc, line| Point2::new(acc.x.max(line.max().x), acc.y.max(line.max().y))),
+        }
+    }
+
+    fn rows(&self) -> RangeInclusive<i32> {
+        self.min.y..=self.max.y
+    }
+
+    fn volume_in_row(&self, row: i32) -> usize {
+        let horizontal_lines = self.lines.iter()
+            .filter(|line| line.direction.is_horizontal())
+            .filter(|line| row >= line.min().y && row <= line.max().y)
+            .map(|line| line.min().x..line.max().x)
+            .collect::<RangeSet<i32>>();
+        let vertical_lines: Vec<Line> = self.lines.iter()
+            .filter(|line| line.direction.is_vertical())
+            .filter(|line| row >= line.min().y && row <= line.max().y)
+            .fold(
+                vec! [],
+                |mut acc, line| {
+                    if let Some(last_line) = acc.last_mut() {
+                        if last_line.direction != line.direction {
+                            acc.push(line.clone());
+                        }
+                    } else {
+                        acc.push(line.clone());
+                    }
+
+                    acc
+                }
+            );
+
+        let mut count = 0;
+        let mut last_intersect = i32::MIN;
+
+        for (num_intersect, line) in vertical_lines.into_iter().enumerate() {
+            if last_intersect < line.min().x {
+                if num_intersect % 2 == 1 {
+                    count += (line.min().x - last_intersect - 1).max(0) as usize;
+                }
+
+                count += (line.max().x - line.min().x + 1) as usize;
+                last_intersect = line.max().x;
+            }
+
+            if let Some(horizontal_line) = horizontal_lines.get(&line.min().x) {
+                count += horizontal_line.len();
+                last_intersect = horizontal_line.end;
+            }
+        }
+
+        count
+    }
+
+    fn volume(&self) -> usize {
+        self.rows()
+            .par_bridge()
+            .map(move |row| self.volume_in_row(row))
+            .sum()
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Vec<DigPlan>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        DigPlan::parse_all(input.lines())
+    }
+
+    fn part1(plan: &Self::Input) -> Result<usize, SolveError> {
+        Ok(Trench::dig(plan.iter().cloned()).volume())
+    }
+
+    fn part2(plan: &Self::Input) -> Result<usize, SolveError> {
+        Ok(Trench::dig(plan.iter().filter_map(|plan| plan.to_hex_plan())).volume())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINES: [&str; 14] = [
+        "R 6 (#70c710)",
+        "D 5 (#0dc571)",
+        "L 2 (#5713f0)",
+        "D 2 (#d2c081)",
+        "R 2 (#59c680)",
+        "D 2 (#411b91)",
+        "L 5 (#8ceee2)",
+        "U 2 (#caa173)",
+        "L 1 (#1b58a2)",
+        "U 2 (#caa171)",
+        "R 2 (#7807d2)",
+        "U 3 (#a77fa3)",
+        "L 2 (#015232)",
+        "U 2 (#7a21e3)",
+    ];
+
+    #[test]
+    fn _01() {
+        let plan = DigPlan::parse_all(LINES.iter().copied()).unwrap();
+        let trench = Trench::dig(plan.into_iter());
+
+        assert_eq!(trench.volume(), 62);
+    }
+
+    #[test]
+    fn _02() {
+        let plan = DigPlan::parse_all(LINES.iter().copied()).unwrap();
+        let trench = Trench::dig(plan.into_iter().filter_map(|plan| plan.to_hex_plan()));
+
+        assert_eq!(trench.volume(), 952408144115);
+    }
+
+    #[test]
+    fn parse_error() {
+        let err = Day18::parse("R 6 (#70c710)\nD 5 (#0dc574)").err().unwrap();
+
+        assert_eq!((err.line(), err.column(), err.text()), (2, 5, "(#0dc574)"));
+    }
+}
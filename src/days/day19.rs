@@ -0,0 +1,625 @@
+use std::{cell::OnceCell, collections::{HashMap, HashSet}, fmt::{self, Display}, ops::Range, rc::Rc};
+
+use crate::{prelude::*, Solution};
+
+/// The names of the rating categories, and the range every rating lies in.
+pub struct Config<const N: usize> {
+    categories: Option<[String; N]>,
+    infer: bool,
+    bounds: Range<u64>,
+}
+
+impl Default for Config<4> {
+    fn default() -> Self {
+        Self::new(["x", "m", "a", "s"], 1..4001)
+    }
+}
+
+impl<const N: usize> Config<N> {
+    pub fn new(categories: [&str; N], bounds: Range<u64>) -> Self {
+        Self { categories: Some(categories.map(str::to_string)), infer: false, bounds }
+    }
+
+    /// Reads the category names from the first part in the input instead.
+    pub fn inferred(bounds: Range<u64>) -> Self {
+        Self { categories: None, infer: true, bounds }
+    }
+
+    /// Like [`Config::inferred`], but keeps the configured names if there are no parts.
+    pub fn or_inferred(self) -> Self {
+        Self { infer: true, ..self }
+    }
+}
+
+fn parse_category(line: &str, s: &str, categories: &[String]) -> Result<usize, ParseError> {
+    categories.iter().position(|category| category == s).ok_or_else(|| {
+        let names = categories.iter().map(|category| format!("`{}`", category)).collect::<Vec<_>>();
+        let expected = match names.split_last() {
+            Some((last, [])) => last.clone(),
+            Some((last, rest)) => format!("one of {} or {}", rest.join(", "), last),
+            None => "a category".to_string(),
+        };
+
+        ParseError::new(line, s, expected)
+    })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Comparison {
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+    Equal,
+    NotEqual,
+}
+
+impl Comparison {
+    /// Longer operators come first so that `<=` is not read as `<`.
+    const ALL: [(&'static str, Self); 6] = [
+        ("<=", Self::LessOrEqual),
+        (">=", Self::GreaterOrEqual),
+        ("==", Self::Equal),
+        ("!=", Self::NotEqual),
+        ("<", Self::Less),
+        (">", Self::Greater),
+    ];
+}
+
+#[derive(Clone, Debug)]
+enum Op {
+    Compare { axis: usize, comparison: Comparison, rhs: u64 },
+    Always,
+}
+
+#[derive(Clone, Debug)]
+struct Rule {
+    op: Op,
+    send_to: String,
+}
+
+impl Rule {
+    fn parse(line: &str, s: &str, categories: &[String]) -> Result<Self, ParseError> {
+        let (op, send_to) = match s.split_once(':') {
+            Some((condition, send_to)) => {
+                let at = condition.find(|ch: char| !ch.is_ascii_alphanumeric())
+                    .ok_or_else(|| ParseError::new(line, condition, "`<category><op><rating>`"))?;
+                let axis = parse_category(line, &condition[..at], categories)?;
+                let rest = &condition[at..];
+                let (operator, comparison) = Comparison::ALL.into_iter()
+                    .find(|(operator, _)| rest.starts_with(operator))
+                    .ok_or_else(|| {
+                        let end = rest.find(|ch: char| ch.is_ascii_alphanumeric()).unwrap_or(rest.len());
+
+                        ParseError::new(line, &rest[..end], "one of `<`, `<=`, `>`, `>=`, `==` or `!=`")
+                    })?;
+                let rhs = parse_token(line, &rest[operator.len()..], "a rating")?;
+
+                (Op::Compare { axis, comparison, rhs }, send_to)
+            },
+            None => (Op::Always, s),
+        };
+
+        if send_to.is_empty() || !send_to.chars().all(|ch| ch.is_ascii_alphabetic()) {
+            return Err(ParseError::new(line, send_to, "a workflow name"));
+        }
+
+        Ok(Self { op, send_to: send_to.to_string() })
+    }
+
+    fn interval(&self) -> Option<(usize, Range<u64>, bool)> {
+        let Op::Compare { axis, comparison, rhs } = self.op else { return None };
+        let (interval, inside) = match comparison {
+            Comparison::Less => (0..rhs, true),
+            Comparison::LessOrEqual => (0..rhs.saturating_add(1), true),
+            Comparison::Greater => (rhs.saturating_add(1)..u64::MAX, true),
+            Comparison::GreaterOrEqual => (rhs..u64::MAX, true),
+            Comparison::Equal => (rhs..rhs.saturating_add(1), true),
+            Comparison::NotEqual => (rhs..rhs.saturating_add(1), false),
+        };
+
+        Some((axis, interval, inside))
+    }
+
+    fn split<const N: usize>(&self, rect: &HyperRect<u64, N>) -> (Vec<HyperRect<u64, N>>, Vec<HyperRect<u64, N>>) {
+        let non_empty = |rects: Vec<HyperRect<u64, N>>| rects.into_iter().filter(|rect| !rect.is_empty()).collect::<Vec<_>>();
+        let Some((axis, interval, inside)) = self.interval() else {
+            return (non_empty(vec! [rect.clone()]), vec! []);
+        };
+        let (lower, rest) = rect.split_at(axis, interval.start);
+        let (middle, upper) = rest.split_at(axis, interval.end);
+
+        if inside {
+            (non_empty(vec! [middle]), non_empty(vec! [lower, upper]))
+        } else {
+            (non_empty(vec! [lower, upper]), non_empty(vec! [middle]))
+        }
+    }
+
+    fn split_all<const N: usize>(&self, rects: &[HyperRect<u64, N>]) -> (Vec<HyperRect<u64, N>>, Vec<HyperRect<u64, N>>) {
+        let mut matched = vec! [];
+        let mut unmatched = vec! [];
+
+        for rect in rects {
+            let (new_matched, new_unmatched) = self.split(rect);
+
+            matched.extend(new_matched);
+            unmatched.extend(new_unmatched);
+        }
+
+        (matched, unmatched)
+    }
+}
+
+pub struct Workflow {
+    name: String,
+    rules: Vec<Rule>,
+}
+
+impl Workflow {
+    fn parse(line: &str, categories: &[String]) -> Result<Self, ParseError> {
+        let (name, rules) = line.split_once('{')
+            .and_then(|(name, rules)| Some((name, rules.strip_suffix('}')?)))
+            .ok_or_else(|| ParseError::new(line, line, "`<name>{<rules>}`"))?;
+        let rules = rules.split(',').map(|s| Rule::parse(line, s, categories)).collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { name: name.to_string(), rules })
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Part<const N: usize> {
+    ratings: [u64; N],
+}
+
+impl<const N: usize> Part<N> {
+    fn fields(line: &str) -> Option<Vec<&str>> {
+        line.strip_prefix('{')
+            .and_then(|s| s.strip_suffix('}'))
+            .map(|s| s.split(',').collect::<Vec<_>>())
+            .filter(|fields| fields.len() == N)
+    }
+
+    fn categories(line: &str) -> Result<[String; N], ParseError> {
+        let fields = Self::fields(line).ok_or_else(|| ParseError::new(line, line, format!("a part with {} ratings", N)))?;
+        let mut categories = std::array::from_fn(|_| String::new());
+
+        for (category, field) in categories.iter_mut().zip(fields) {
+            let (name, _) = field.split_once('=').ok_or_else(|| ParseError::new(line, field, "`<category>=<rating>`"))?;
+
+            *category = name.to_string();
+        }
+
+        Ok(categories)
+    }
+
+    fn parse(line: &str, categories: &[String; N], bounds: &Range<u64>) -> Result<Self, ParseError> {
+        let expected = format!("`{{{}}}`", categories.iter().map(|name| format!("{}=<rating>", name)).collect::<Vec<_>>().join(","));
+        let fields = Self::fields(line).ok_or_else(|| ParseError::new(line, line, expected))?;
+        let mut ratings = [0; N];
+
+        for (rating, (field, name)) in ratings.iter_mut().zip(fields.into_iter().zip(categories)) {
+            let value = field.strip_prefix(name.as_str())
+                .and_then(|s| s.strip_prefix('='))
+                .ok_or_else(|| ParseError::new(line, field, format!("`{}=<rating>`", name)))?;
+
+            *rating = parse_token::<u64>(line, value, "a rating")
+                .and_then(|rating| {
+                    Some(rating)
+                        .filter(|rating| bounds.contains(rating))
+                        .ok_or_else(|| ParseError::new(line, value, format!("a rating within {}..{}", bounds.start, bounds.end)))
+                })?;
+        }
+
+        Ok(Self { ratings })
+    }
+
+    fn total_rating(&self) -> u64 {
+        self.ratings.iter().sum()
+    }
+}
+
+/// The workflows and parts of an input with `N` rating categories.
+pub struct System<const N: usize> {
+    bounds: Range<u64>,
+    workflows: Vec<Workflow>,
+    parts: Vec<Part<N>>,
+    tree: OnceCell<Result<DecisionTree<N>, SolveError>>,
+}
+
+impl<const N: usize> System<N> {
+    pub fn parse(input: &str, config: &Config<N>) -> Result<Self, ParseError> {
+        let lines = input.lines().enumerate().collect::<Vec<_>>();
+        let separator = lines.iter().position(|(_, line)| line.is_empty()).unwrap_or(lines.len());
+        let (workflow_lines, part_lines) = (&lines[..separator], lines.get(separator + 1..).unwrap_or(&[]));
+        let categories = match (part_lines.first(), &config.categories) {
+            (Some(&(i, line)), _) if config.infer => Part::<N>::categories(line).map_err(|err| err.at_line(i + 1))?,
+            (_, Some(categories)) => categories.clone(),
+            (_, None) => return Err(ParseError::end_of_input("a part to read the categories from").at_line(lines.len())),
+        };
+        let workflows = workflow_lines.iter()
+            .map(|&(i, line)| Workflow::parse(line, &categories).map_err(|err| err.at_line(i + 1)))
+            .collect::<Result<Vec<_>, _>>()?;
+        let parts = part_lines.iter()
+            .map(|&(i, line)| Part::parse(line, &categories, &config.bounds).map_err(|err| err.at_line(i + 1)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self { bounds: config.bounds.clone(), workflows, parts, tree: OnceCell::new() })
+    }
+
+    pub fn all(&self) -> HyperRect<u64, N> {
+        HyperRect::new(std::array::from_fn(|_| self.bounds.clone()))
+    }
+
+    fn workflow(&self, name: &str) -> Option<&Workflow> {
+        self.workflows.iter().find(|workflow| workflow.name == name)
+    }
+
+    pub fn compile(&self) -> Result<&DecisionTree<N>, SolveError> {
+        self.tree.get_or_init(|| {
+            let root = self.compile_workflow("in", &mut HashMap::new(), &mut vec! [])?;
+
+            Ok(DecisionTree { root, bounds: self.all() })
+        }).as_ref().map_err(Clone::clone)
+    }
+
+    fn compile_workflow(
+        &self,
+        name: &str,
+        compiled: &mut HashMap<String, Rc<Node>>,
+        visiting: &mut Vec<String>
+    ) -> Result<Rc<Node>, SolveError>
+    {
+        if let Some(node) = compiled.get(name) {
+            return Ok(node.clone());
+        } else if visiting.iter().any(|other| other == name) {
+            return Err(SolveError::Cycle(name.to_string()));
+        }
+
+        let workflow = self.workflow(name).ok_or_else(|| SolveError::MissingReference(name.to_string()))?;
+
+        visiting.push(name.to_string());
+
+        let mut node = Rc::new(Node::Reject);
+
+        for rule in workflow.rules.iter().rev() {
+            let target = match rule.send_to.as_str() {
+                "A" => Rc::new(Node::Accept),
+                "R" => Rc::new(Node::Reject),
+                send_to => self.compile_workflow(send_to, compiled, visiting)?,
+            };
+
+            node = match rule.interval() {
+                None => target,
+                Some((axis, interval, inside)) => {
+                    let (inner, outer) = if inside { (target, node) } else { (node, target) };
+                    let upper = if interval.end == u64::MAX {
+                        inner
+                    } else {
+                        Rc::new(Node::Branch { axis, value: interval.end, below: inner, at_or_above: outer.clone() })
+                    };
+
+                    if interval.start == 0 {
+                        upper
+                    } else {
+                        Rc::new(Node::Branch { axis, value: interval.start, below: outer, at_or_above: upper })
+                    }
+                },
+            };
+        }
+
+        visiting.pop();
+        compiled.insert(name.to_string(), node.clone());
+
+        Ok(node)
+    }
+
+    /// Finds unreachable workflows, rules that never match and undefined references.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = vec! [];
+        let mut reached = HashSet::new();
+
+        self.reach("in", vec! [self.all()], &mut reached, &mut vec! []);
+
+        for workflow in &self.workflows {
+            if !reached.contains(&workflow.name) {
+                diagnostics.push(Diagnostic::UnreachableWorkflow(workflow.name.clone()));
+            }
+
+            workflow.rules.iter().enumerate().fold(vec! [self.all()], |leftovers, (i, rule)| {
+                let (matched, leftovers) = rule.split_all(&leftovers);
+
+                if matched.is_empty() {
+                    diagnostics.push(Diagnostic::ShadowedRule { workflow: workflow.name.clone(), rule: i + 1 });
+                }
+
+                leftovers
+            });
+
+            for rule in &workflow.rules {
+                if !["A", "R"].contains(&rule.send_to.as_str()) && self.workflow(&rule.send_to).is_none() {
+                    diagnostics.push(Diagnostic::UndefinedReference { workflow: workflow.name.clone(), target: rule.send_to.clone() });
+                }
+            }
+        }
+
+        diagnostics
+    }
+
+    fn reach(&self, name: &str, rects: Vec<HyperRect<u64, N>>, reached: &mut HashSet<String>, visiting: &mut Vec<String>) {
+        let Some(workflow) = self.workflow(name) else { return };
+
+        if visiting.iter().any(|other| other == name) {
+            return;
+        }
+
+        reached.insert(name.to_string());
+        visiting.push(name.to_string());
+        workflow.rules.iter().fold(rects, |leftovers, rule| {
+            let (matched, leftovers) = rule.split_all(&leftovers);
+
+            if !matched.is_empty() {
+                self.reach(&rule.send_to, matched, reached, visiting);
+            }
+
+            leftovers
+        });
+        visiting.pop();
+    }
+
+    pub fn num_accepted(&self, rect: &HyperRect<u64, N>) -> Result<u64, SolveError> {
+        Ok(self.compile()?.num_accepted(rect))
+    }
+
+    fn total_accepted_rating(&self) -> Result<u64, SolveError> {
+        let tree = self.compile()?;
+
+        Ok(self.parts.iter().filter(|part| tree.accepts(&part.ratings)).map(|part| part.total_rating()).sum())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Diagnostic {
+    UnreachableWorkflow(String),
+    ShadowedRule { workflow: String, rule: usize },
+    UndefinedReference { workflow: String, target: String },
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnreachableWorkflow(name) => write!(f, "workflow `{}` is unreachable", name),
+            Self::ShadowedRule { workflow, rule } => write!(f, "rule {} of workflow `{}` never matches", rule, workflow),
+            Self::UndefinedReference { workflow, target } => write!(f, "workflow `{}` refers to undefined `{}`", workflow, target),
+        }
+    }
+}
+
+#[derive(Debug)]
+enum Node {
+    Accept,
+    Reject,
+    Branch { axis: usize, value: u64, below: Rc<Node>, at_or_above: Rc<Node> },
+}
+
+/// The workflows compiled into a binary tree that splits the ratings one axis at a time.
+pub struct DecisionTree<const N: usize> {
+    root: Rc<Node>,
+    bounds: HyperRect<u64, N>,
+}
+
+impl<const N: usize> DecisionTree<N> {
+    pub fn accepts(&self, ratings: &[u64; N]) -> bool {
+        let mut node = &self.root;
+
+        loop {
+            match node.as_ref() {
+                Node::Accept => return true,
+                Node::Reject => return false,
+                Node::Branch { axis, value, below, at_or_above } => {
+                    node = if ratings[*axis] < *value { below } else { at_or_above };
+                },
+            }
+        }
+    }
+
+    pub fn accepted_regions(&self) -> Vec<HyperRect<u64, N>> {
+        let mut regions = vec! [];
+        let mut remaining = vec! [(&self.root, self.bounds.clone())];
+
+        while let Some((node, rect)) = remaining.pop() {
+            match node.as_ref() {
+                _ if rect.is_empty() => {},
+                Node::Accept => regions.push(rect),
+                Node::Reject => {},
+                Node::Branch { axis, value, below, at_or_above } => {
+                    let (lower, upper) = rect.split_at(*axis, *value);
+
+                    remaining.push((at_or_above, upper));
+                    remaining.push((below, lower));
+                },
+            }
+        }
+
+        regions
+    }
+
+    /// The number of rating combinations within `rect` that are accepted.
+    pub fn num_accepted(&self, rect: &HyperRect<u64, N>) -> u64 {
+        self.accepted_regions().iter().map(|region| region.intersection(rect).volume()).sum()
+    }
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = System<4>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        System::parse(input, &Config::default().or_inferred())
+    }
+
+    fn part1(system: &Self::Input) -> Result<u64, SolveError> {
+        system.total_accepted_rating()
+    }
+
+    fn part2(system: &Self::Input) -> Result<u64, SolveError> {
+        system.num_accepted(&system.all())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINES: [&str; 17] = [
+        "px{a<2006:qkq,m>2090:A,rfg}",
+        "pv{a>1716:R,A}",
+        "lnx{m>1548:A,A}",
+        "rfg{s<537:gd,x>2440:R,A}",
+        "qs{s>3448:A,lnx}",
+        "qkq{x<1416:A,crn}",
+        "crn{x>2662:A,R}",
+        "in{s<1351:px,qqz}",
+        "qqz{s>2770:qs,m<1801:hdj,R}",
+        "gd{a>3333:R,R}",
+        "hdj{m>838:A,pv}",
+        "",
+        "{x=787,m=2655,a=1222,s=2876}",
+        "{x=1679,m=44,a=2067,s=496}",
+        "{x=2036,m=264,a=79,s=2244}",
+        "{x=2461,m=1339,a=466,s=291}",
+        "{x=2127,m=1623,a=2188,s=1013}",
+    ];
+
+    #[test]
+    fn _01() {
+        let system = System::parse(&LINES.join("\n"), &Config::default()).unwrap();
+
+        assert_eq!(system.total_accepted_rating(), Ok(19114));
+    }
+
+    #[test]
+    fn _02() {
+        let system = System::parse(&LINES.join("\n"), &Config::default()).unwrap();
+
+        assert_eq!(system.num_accepted(&system.all()), Ok(167409079868000));
+    }
+
+    #[test]
+    fn decision_tree() {
+        let system = System::parse(&LINES.join("\n"), &Config::default()).unwrap();
+        let tree = system.compile().unwrap();
+        let regions = tree.accepted_regions();
+
+        assert_eq!(system.parts.iter().map(|part| tree.accepts(&part.ratings)).collect::<Vec<_>>(), vec! [true, false, true, false, true]);
+        assert_eq!(regions.iter().map(|region| region.volume()).sum::<u64>(), 167409079868000);
+        assert!(regions.iter().all(|region| regions.iter().filter(|other| !region.intersection(other).is_empty()).count() == 1));
+        assert!(system.diagnostics().is_empty());
+    }
+
+    #[test]
+    fn diagnostics() {
+        let system = Day19::parse("in{x<10:a,x<5:R,b}\na{m>5:A,m>7:A,R}\nb{s<3:c,A}\nd{A}").unwrap();
+
+        assert_eq!(system.diagnostics(), vec! [
+            Diagnostic::ShadowedRule { workflow: "in".to_string(), rule: 2 },
+            Diagnostic::ShadowedRule { workflow: "a".to_string(), rule: 2 },
+            Diagnostic::UndefinedReference { workflow: "b".to_string(), target: "c".to_string() },
+            Diagnostic::UnreachableWorkflow("d".to_string()),
+        ]);
+        assert_eq!(system.compile().err(), Some(SolveError::MissingReference("c".to_string())));
+
+        let system = Day19::parse("in{x<10:a,A}\na{b}\nb{m<5:a,R}").unwrap();
+
+        assert_eq!(system.compile().err(), Some(SolveError::Cycle("a".to_string())));
+
+        let system = Day19::parse("in{x<10:a,A}\na{x>20:b,R}\nb{A}").unwrap();
+
+        assert_eq!(system.diagnostics(), vec! [Diagnostic::UnreachableWorkflow("b".to_string())]);
+    }
+
+    #[test]
+    fn comparisons() {
+        let system = Day19::parse("in{x<=10:A,m>=3990:A,a==5:A,s!=7:R,A}").unwrap();
+        let tree = system.compile().unwrap();
+        let all = 4000u64.pow(4);
+        let rejected = 3990 * 3989 * 3999 * 3999;
+
+        assert!(tree.accepts(&[10, 1, 1, 7]));
+        assert!(!tree.accepts(&[11, 1, 1, 6]));
+        assert!(tree.accepts(&[11, 1, 1, 7]));
+        assert!(tree.accepts(&[11, 3990, 1, 1]));
+        assert!(tree.accepts(&[11, 1, 5, 1]));
+        assert_eq!(system.num_accepted(&system.all()), Ok(all - rejected));
+        assert!(system.diagnostics().is_empty());
+
+        let system = Day19::parse("in{s!=7:R,s==7:A,R}").unwrap();
+
+        assert_eq!(system.num_accepted(&system.all()), Ok(4000 * 4000 * 4000));
+        assert_eq!(system.diagnostics(), vec! [Diagnostic::ShadowedRule { workflow: "in".to_string(), rule: 3 }]);
+    }
+
+    #[test]
+    fn unknown_operator() {
+        let err = Day19::parse("in{x<5:A,m=>7:A,R}").err().unwrap();
+
+        assert_eq!((err.line(), err.column(), err.text()), (1, 11, "=>"));
+
+        let err = Day19::parse("in{x<5:A,m~7:A,R}").err().unwrap();
+
+        assert_eq!((err.line(), err.column(), err.text()), (1, 11, "~"));
+
+        let err = Day19::parse("in{x<5:A,x<=:A,R}").err().unwrap();
+
+        assert_eq!(err.expected(), "a rating");
+    }
+
+    #[test]
+    fn other_categories() {
+        let input = "in{b>4:A,c<2:A,R}\n\n{a=1,b=5,c=3}\n{a=2,b=1,c=1}\n{a=3,b=1,c=3}";
+        let system = System::parse(input, &Config::inferred(0..10)).unwrap();
+
+        assert_eq!(system.total_accepted_rating(), Ok(13));
+        assert_eq!(system.num_accepted(&system.all()), Ok(10 * 5 * 10 + 10 * 5 * 2));
+        assert_eq!(system.num_accepted(&HyperRect::new([0..1, 0..1, 0..1])), Ok(1));
+
+        let system = System::parse("in{y<2:A,R}", &Config::new(["y"], 0..4)).unwrap();
+
+        assert_eq!(system.num_accepted(&system.all()), Ok(2));
+        assert!(System::<1>::parse("in{y<2:A,R}", &Config::inferred(0..4)).is_err());
+
+        let system = Day19::parse("in{p>2000:A,R}\n\n{p=2001,q=1,r=1,t=1}\n{p=1,q=1,r=1,t=1}").unwrap();
+
+        assert_eq!(Day19::part1(&system), Ok(2004));
+        assert_eq!(Day19::part2(&system), Ok(2000 * 4000 * 4000 * 4000));
+    }
+
+    #[test]
+    fn parse_error() {
+        let err = Day19::parse("in{s<1351:A,R}\n\n{x=1,m=1,a=1,s=1}\n{x=787,m=2655,b=1222,s=2876}").err().unwrap();
+
+        assert_eq!((err.line(), err.column(), err.text()), (4, 15, "b=1222"));
+
+        let err = System::parse("in{s<1351:A,R}\n\n{x=787,m=2655,b=1222,s=2876}", &Config::default()).err().unwrap();
+
+        assert_eq!((err.line(), err.column(), err.text()), (3, 15, "b=1222"));
+
+        let err = Day19::parse("in{s<1351:A,q>5:R,R}").err().unwrap();
+
+        assert_eq!((err.line(), err.column(), err.text()), (1, 13, "q"));
+
+        let err = Day19::parse("in{s<1351:A,R}\n\n{x=787,m=2655,a=1222,s=4001}").err().unwrap();
+
+        assert_eq!((err.line(), err.column(), err.text()), (3, 24, "4001"));
+    }
+
+    #[test]
+    fn missing_workflow() {
+        let input = Day19::parse("in{s<1351:px,A}\n\n{x=787,m=2655,a=1222,s=2876}").unwrap();
+
+        assert_eq!(Day19::part2(&input), Err(SolveError::MissingReference("px".to_string())));
+    }
+}
@@ -0,0 +1,765 @@
+use std::{any::Any, collections::{BTreeSet, VecDeque, HashMap}, fmt::{self, Write}, io};
+
+use crate::{prelude::*, Solution};
+
+const BUTTON: &str = "button";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pulse {
+    High,
+    Low,
+}
+
+/// A kind of module, which decides what to send whenever it receives a pulse.
+pub trait PulseModule: Any + fmt::Debug + CloneModule {
+    fn add_input(&mut self, _name: &str) {}
+
+    fn receive(&mut self, from: &str, pulse: Pulse) -> Option<Pulse>;
+
+    fn state(&self) -> Vec<bool> {
+        vec! []
+    }
+
+    fn shape(&self) -> &'static str {
+        "ellipse"
+    }
+}
+
+pub trait CloneModule {
+    fn clone_module(&self) -> Box<dyn PulseModule>;
+}
+
+impl<T: PulseModule + Clone> CloneModule for T {
+    fn clone_module(&self) -> Box<dyn PulseModule> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn PulseModule> {
+    fn clone(&self) -> Self {
+        self.clone_module()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Broadcaster;
+
+impl PulseModule for Broadcaster {
+    fn receive(&mut self, _from: &str, pulse: Pulse) -> Option<Pulse> {
+        Some(pulse)
+    }
+
+    fn shape(&self) -> &'static str {
+        "doubleoctagon"
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct FlipFlop {
+    on: bool,
+}
+
+impl PulseModule for FlipFlop {
+    fn receive(&mut self, _from: &str, pulse: Pulse) -> Option<Pulse> {
+        if pulse == Pulse::High {
+            None
+        } else {
+            self.on = !self.on;
+            Some(if self.on { Pulse::High } else { Pulse::Low })
+        }
+    }
+
+    fn state(&self) -> Vec<bool> {
+        vec! [self.on]
+    }
+
+    fn shape(&self) -> &'static str {
+        "box"
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Conjunction {
+    memory: HashMap<String, Pulse>,
+}
+
+impl PulseModule for Conjunction {
+    fn add_input(&mut self, name: &str) {
+        self.memory.entry(name.to_string()).or_insert(Pulse::Low);
+    }
+
+    fn receive(&mut self, from: &str, pulse: Pulse) -> Option<Pulse> {
+        self.memory.insert(from.to_string(), pulse);
+
+        if self.memory.values().all(|&s| s == Pulse::High) {
+            Some(Pulse::Low)
+        } else {
+            Some(Pulse::High)
+        }
+    }
+
+    fn state(&self) -> Vec<bool> {
+        let mut inputs = self.memory.iter().collect::<Vec<_>>();
+
+        inputs.sort_unstable_by_key(|&(name, _)| name);
+        inputs.into_iter().map(|(_, &pulse)| pulse == Pulse::High).collect()
+    }
+
+    fn shape(&self) -> &'static str {
+        "diamond"
+    }
+}
+
+type Constructor = Box<dyn Fn() -> Box<dyn PulseModule>>;
+
+pub struct Registry {
+    kinds: Vec<(char, Constructor)>,
+}
+
+impl Default for Registry {
+    fn default() -> Self {
+        Self::new()
+            .with('%', FlipFlop::default)
+            .with('&', Conjunction::default)
+    }
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        Self { kinds: vec! [] }
+    }
+
+    pub fn with<M: PulseModule>(mut self, prefix: char, constructor: impl Fn() -> M + 'static) -> Self {
+        self.kinds.retain(|&(other, _)| other != prefix);
+        self.kinds.push((prefix, Box::new(move || Box::new(constructor()))));
+        self
+    }
+
+    fn expected(&self) -> String {
+        let kinds = std::iter::once("`broadcaster`".to_string())
+            .chain(self.kinds.iter().map(|(prefix, _)| format!("`{}<name>`", prefix)))
+            .collect::<Vec<_>>();
+
+        match kinds.split_last() {
+            Some((last, rest)) if !rest.is_empty() => format!("{} or {}", rest.join(", "), last),
+            _ => kinds.join(""),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Module {
+    name: String,
+    kind: Box<dyn PulseModule>,
+    destination: Vec<String>,
+}
+
+impl Module {
+    fn parse(s: &str, registry: &Registry) -> Result<Self, ParseError> {
+        let (name, destination) = s.split_once(" -> ").ok_or_else(|| ParseError::new(s, s, "`<module> -> <destinations>`"))?;
+        let destination = destination.split(", ").map(|s| s.trim().to_string()).collect();
+
+        if name == "broadcaster" {
+            return Ok(Self { name: name.to_string(), kind: Box::new(Broadcaster), destination });
+        }
+
+        registry.kinds.iter()
+            .find_map(|(prefix, constructor)| name.strip_prefix(*prefix).map(|name| Self { name: name.to_string(), kind: constructor(), destination: destination.clone() }))
+            .ok_or_else(|| ParseError::new(s, name, registry.expected()))
+    }
+
+    fn parse_all(lines: impl Iterator<Item=String>) -> Result<HashMap<String, Self>, ParseError> {
+        Self::parse_all_with(lines, &Registry::default())
+    }
+
+    pub fn parse_all_with(lines: impl Iterator<Item=String>, registry: &Registry) -> Result<HashMap<String, Self>, ParseError> {
+        let modules = lines.enumerate()
+            .map(|(i, s)| Self::parse(&s, registry).map_err(|err| err.at_line(i + 1)))
+            .map(|m| m.map(|m| (m.name.clone(), m)))
+            .collect::<Result<_, _>>()?;
+
+        Ok(connect(modules))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn downcast_ref<M: PulseModule>(&self) -> Option<&M> {
+        (self.kind.as_ref() as &dyn Any).downcast_ref()
+    }
+
+    fn state(&self) -> Vec<bool> {
+        self.kind.state()
+    }
+
+    fn add_input(&mut self, name: &str) {
+        self.kind.add_input(name);
+    }
+
+    fn receive(&mut self, from: &str, signal: Pulse) -> Option<Pulse> {
+        self.kind.receive(from, signal)
+    }
+}
+
+fn connect(mut modules: HashMap<String, Module>) -> HashMap<String, Module> {
+    let module_names = modules.values().map(|module| module.name.clone()).collect::<Vec<_>>();
+
+    for name in module_names.into_iter() {
+        let destinations = modules.get(&name).unwrap().destination.clone();
+
+        for destination in &destinations {
+            if let Some(module) = modules.get_mut(destination) {
+                module.add_input(&name);
+            }
+        }
+    }
+
+    modules
+}
+
+fn propagate(modules: &mut HashMap<String, Module>, signal: Pulse, mut visit: impl FnMut(&str, &str, Pulse) -> bool) -> bool {
+    let mut remaining = VecDeque::from([(BUTTON.to_string(), "broadcaster".to_string(), signal)]);
+
+    while let Some((from, to, signal)) = remaining.pop_front() {
+        if !visit(&from, &to, signal) {
+            return false;
+        }
+
+        if let Some((output, destinations)) = modules.get_mut(&to).and_then(|module| module.receive(&from, signal).map(|output| (output, &module.destination))) {
+            for destination in destinations {
+                remaining.push_back((to.clone(), destination.clone(), output));
+            }
+        }
+    }
+
+    true
+}
+
+fn process(modules: &mut HashMap<String, Module>, signal: Pulse, terminate_at: impl Fn(&str, Pulse) -> bool) -> Option<(usize, usize)> {
+    let mut count = (0, 0);
+    let settled = propagate(modules, signal, |from, _, signal| {
+        if signal == Pulse::High {
+            count.1 += 1;
+        } else {
+            count.0 += 1;
+        }
+
+        !terminate_at(from, signal)
+    });
+
+    settled.then_some(count)
+}
+
+fn process_n(modules: &mut HashMap<String, Module>, signal: Pulse, n: usize) -> usize {
+    let count = (0..n).fold((0, 0), |acc, _| {
+        let result = process(modules, signal, |_, _| false).unwrap();
+
+        (acc.0 + result.0, acc.1 + result.1)
+    });
+
+    count.0 * count.1
+}
+
+fn upstream(modules: &HashMap<String, Module>) -> HashMap<&str, Vec<&str>> {
+    modules.keys().map(|name| {
+        let mut found = BTreeSet::from([name.as_str()]);
+        let mut remaining = vec! [name.as_str()];
+
+        while let Some(to) = remaining.pop() {
+            for module in modules.values() {
+                if module.destination.iter().any(|d| d == to) && found.insert(module.name.as_str()) {
+                    remaining.push(&module.name);
+                }
+            }
+        }
+
+        (name.as_str(), found.into_iter().collect())
+    }).collect()
+}
+
+pub fn sub_counters<'a>(modules: &'a HashMap<String, Module>, outputs: &[String]) -> Vec<BTreeSet<&'a str>> {
+    let upstream = upstream(modules);
+    let cones = outputs.iter()
+        .map(|name| upstream.get(name.as_str()).into_iter().flatten().copied().collect::<BTreeSet<_>>())
+        .collect::<Vec<_>>();
+
+    cones.iter().enumerate()
+        .map(|(i, cone)| cone.iter().copied().filter(|name| cones.iter().enumerate().all(|(j, other)| i == j || !other.contains(name))).collect())
+        .collect()
+}
+
+const COLOURS: [&str; 8] = ["lightblue", "lightpink", "palegreen", "khaki", "plum", "lightsalmon", "paleturquoise", "wheat"];
+
+pub fn to_dot(modules: &HashMap<String, Module>, groups: &[BTreeSet<&str>]) -> String {
+    let names = modules.values()
+        .flat_map(|module| module.destination.iter().chain([&module.name]))
+        .map(String::as_str)
+        .collect::<BTreeSet<_>>();
+    let mut dot = String::new();
+
+    writeln!(dot, "digraph modules {{").unwrap();
+
+    for name in &names {
+        let shape = modules.get(*name).map_or("plaintext", |module| module.kind.shape());
+        let fill = groups.iter().position(|group| group.contains(name))
+            .map(|i| format!(", style=filled, fillcolor={}", COLOURS[i % COLOURS.len()]))
+            .unwrap_or_default();
+
+        writeln!(dot, "    \"{}\" [shape={}{}];", name, shape, fill).unwrap();
+    }
+
+    for module in names.iter().filter_map(|&name| modules.get(name)) {
+        for destination in &module.destination {
+            writeln!(dot, "    \"{}\" -> \"{}\";", module.name, destination).unwrap();
+        }
+    }
+
+    writeln!(dot, "}}").unwrap();
+    dot
+}
+
+pub fn dependencies(modules: &HashMap<String, Module>, module: &str) -> Vec<String> {
+    let mut remaining = vec! [module.to_string()];
+
+    while remaining.len() == 1 {
+        let name = remaining.pop().unwrap();
+
+        remaining.extend(modules.values().filter_map(|module| {
+            if module.destination.contains(&name) {
+                Some(module.name.clone())
+            } else {
+                None
+            }
+        }));
+    }
+
+    remaining
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PulseEvent {
+    pub press: usize,
+    pub from: String,
+    pub to: String,
+    pub pulse: Pulse,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Period {
+    pub offset: usize,
+    pub length: usize,
+}
+
+/// Presses the button on a network of modules, one press at a time.
+#[derive(Debug, Clone)]
+pub struct Simulator {
+    modules: HashMap<String, Module>,
+    presses: usize,
+}
+
+impl Simulator {
+    pub fn new(modules: HashMap<String, Module>) -> Self {
+        Self { modules, presses: 0 }
+    }
+
+    pub fn module(&self, name: &str) -> Option<&Module> {
+        self.modules.get(name)
+    }
+
+    pub fn presses(&self) -> usize {
+        self.presses
+    }
+
+    pub fn press(&mut self) -> Vec<PulseEvent> {
+        let mut trace = vec! [];
+
+        self.presses += 1;
+        propagate(&mut self.modules, Pulse::Low, |from, to, pulse| {
+            trace.push(PulseEvent { press: self.presses, from: from.to_string(), to: to.to_string(), pulse });
+            true
+        });
+
+        trace
+    }
+
+    pub fn press_n(&mut self, n: usize) -> Vec<PulseEvent> {
+        (0..n).flat_map(|_| self.press()).collect()
+    }
+
+    pub fn periods(&self, max_presses: usize) -> HashMap<String, Period> {
+        let upstream = upstream(&self.modules);
+        let mut simulator = self.clone();
+        let mut seen = upstream.keys().map(|&name| (name, HashMap::new())).collect::<HashMap<_, _>>();
+        let mut periods = HashMap::new();
+
+        for press in 0..=max_presses {
+            seen.retain(|&name, seen| {
+                let state = upstream[name].iter()
+                    .flat_map(|&name| simulator.modules[name].state())
+                    .collect::<Vec<_>>();
+
+                if let Some(&offset) = seen.get(&state) {
+                    periods.insert(name.to_string(), Period { offset, length: press - offset });
+                    false
+                } else {
+                    seen.insert(state, press);
+                    true
+                }
+            });
+
+            if seen.is_empty() {
+                break;
+            }
+
+            simulator.press();
+        }
+
+        periods
+    }
+}
+
+fn vcd_identifier(mut index: usize) -> String {
+    let mut identifier = String::new();
+
+    loop {
+        identifier.push((b'!' + (index % 94) as u8) as char);
+        index /= 94;
+
+        if index == 0 {
+            return identifier;
+        }
+
+        index -= 1;
+    }
+}
+
+/// Writes a pulse trace as a VCD waveform, with one wire per sending module.
+pub fn write_vcd(trace: &[PulseEvent], mut out: impl io::Write) -> io::Result<()> {
+    let senders = trace.iter().map(|event| event.from.as_str()).collect::<BTreeSet<_>>();
+    let identifiers = senders.iter().enumerate().map(|(i, &name)| (name, vcd_identifier(i + 1))).collect::<HashMap<_, _>>();
+    let press = vcd_identifier(0);
+
+    writeln!(out, "$timescale 1ns $end")?;
+    writeln!(out, "$scope module network $end")?;
+    writeln!(out, "$var integer 32 {} press $end", press)?;
+
+    for name in &senders {
+        writeln!(out, "$var wire 1 {} {} $end", identifiers[name], name)?;
+    }
+
+    writeln!(out, "$upscope $end")?;
+    writeln!(out, "$enddefinitions $end")?;
+    writeln!(out, "$dumpvars")?;
+    writeln!(out, "b0 {}", press)?;
+
+    for name in &senders {
+        writeln!(out, "0{}", identifiers[name])?;
+    }
+
+    writeln!(out, "$end")?;
+
+    let mut last_press = 0;
+
+    for (time, event) in trace.iter().enumerate() {
+        writeln!(out, "#{}", time)?;
+
+        if event.press != last_press {
+            writeln!(out, "b{:b} {}", event.press, press)?;
+            last_press = event.press;
+        }
+
+        writeln!(out, "{}{}", if event.pulse == Pulse::High { 1 } else { 0 }, identifiers[event.from.as_str()])?;
+    }
+
+    writeln!(out, "#{}", trace.len())
+}
+
+pub const PRESSES: usize = 1000;
+
+const MAX_PRESSES: usize = 1 << 14;
+
+fn senders<'a>(modules: &'a HashMap<String, Module>, name: &str) -> Vec<&'a str> {
+    modules.values()
+        .filter(|module| module.destination.iter().any(|d| d == name))
+        .map(|module| module.name.as_str())
+        .collect()
+}
+
+fn combine_counters(modules: &HashMap<String, Module>) -> Option<Result<usize, SolveError>> {
+    let [conjunction] = senders(modules, "rx")[..] else {
+        return None;
+    };
+
+    modules[conjunction].downcast_ref::<Conjunction>()?;
+
+    let inputs = senders(modules, conjunction);
+    let upstream = upstream(modules);
+    let shares_state = |a: &str, b: &str| upstream[a].iter().any(|name| upstream[b].contains(name) && !modules[*name].state().is_empty());
+
+    if inputs.iter().any(|&input| upstream[input].contains(&conjunction)) || inputs.iter().enumerate().any(|(i, &a)| inputs[i + 1..].iter().any(|&b| shares_state(a, b))) {
+        return None;
+    }
+
+    let periods = Simulator::new(modules.clone()).periods(MAX_PRESSES);
+    let periods = inputs.iter().map(|&input| periods.get(input).copied()).collect::<Option<Vec<_>>>()?;
+    let mut simulator = Simulator::new(modules.clone());
+    let mut highs = vec! [vec! []; inputs.len()];
+
+    while simulator.presses() < periods.iter().map(|period| period.offset + period.length).max().unwrap_or(0) {
+        let mut last = vec! [None; inputs.len()];
+
+        for event in simulator.press().into_iter().filter(|event| event.to == conjunction) {
+            let i = inputs.iter().position(|&input| input == event.from).unwrap();
+
+            if event.pulse == Pulse::High && event.press <= periods[i].offset + periods[i].length && highs[i].last() != Some(&event.press) {
+                highs[i].push(event.press);
+            }
+
+            last[i] = Some(event.pulse);
+        }
+
+        if last.contains(&Some(Pulse::High)) {
+            return None;
+        }
+    }
+
+    let congruences = highs.iter().zip(&periods)
+        .map(|(highs, period)| match highs[..] {
+            [press] if press > period.offset => Some((press as u64, period.length as u64)),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let earliest = congruences.iter().map(|&(press, _)| press).max().unwrap_or(1);
+
+    Some(crt(congruences)
+        .map(|(remainder, modulus)| (remainder + earliest.saturating_sub(remainder).div_ceil(modulus) * modulus) as usize)
+        .ok_or_else(|| SolveError::UnreachableTarget(format!("a press where every input of `{}` is high", conjunction))))
+}
+
+fn press_until_rx(modules: &HashMap<String, Module>, max_presses: usize) -> Result<usize, SolveError> {
+    let mut modules = modules.clone();
+
+    (1..=max_presses)
+        .find(|_| !propagate(&mut modules, Pulse::Low, |_, to, pulse| to != "rx" || pulse == Pulse::High))
+        .ok_or_else(|| SolveError::UnreachableTarget(format!("a low pulse to `rx` within {} presses", max_presses)))
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = HashMap<String, Module>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Module::parse_all(input.lines().map(str::to_string))
+    }
+
+    fn part1(modules: &Self::Input) -> Result<usize, SolveError> {
+        Ok(process_n(&mut modules.clone(), Pulse::Low, PRESSES))
+    }
+
+    fn part2(modules: &Self::Input) -> Result<usize, SolveError> {
+        combine_counters(modules).unwrap_or_else(|| press_until_rx(modules, MAX_PRESSES))
+    }
+
+    fn render(modules: &Self::Input, part: u32) -> Option<Result<String, SolveError>> {
+        match part {
+            1 => Some(Ok(to_dot(modules, &[]))),
+            2 => Some(Ok(to_dot(modules, &sub_counters(modules, &dependencies(modules, "rx"))))),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINES_1: [&str; 5] = [
+        "broadcaster -> a, b, c",
+        "%a -> b",
+        "%b -> c",
+        "%c -> inv",
+        "&inv -> a",
+    ];
+
+    const LINES_2: [&str; 5] = [
+        "broadcaster -> a",
+        "%a -> inv, con",
+        "&inv -> b",
+        "%b -> con",
+        "&con -> output",
+    ];
+
+    #[test]
+    fn _01_1() {
+        let modules = Module::parse_all(LINES_1.iter().map(|s| s.to_string())).unwrap();
+
+        assert_eq!(process(&mut modules.clone(), Pulse::Low, |_, _| false), Some((8, 4)));
+        assert_eq!(process_n(&mut modules.clone(), Pulse::Low, 1000), 32000000);
+    }
+
+    #[test]
+    fn _01_2() {
+        let mut modules = Module::parse_all(LINES_2.iter().map(|s| s.to_string())).unwrap();
+
+        assert_eq!(process_n(&mut modules, Pulse::Low, 1000), 11687500);
+    }
+
+    const LINES_3: [&str; 12] = [
+        "broadcaster -> a0, b0",
+        "%a0 -> a1, c0",
+        "%a1 -> a2",
+        "%a2 -> c0",
+        "&c0 -> a1, a0, i0",
+        "&i0 -> fin",
+        "%b0 -> b1, c1",
+        "%b1 -> b2, c1",
+        "%b2 -> c1",
+        "&c1 -> b0, i1",
+        "&i1 -> fin",
+        "&fin -> rx",
+    ];
+
+    #[test]
+    fn _02() {
+        let modules = Module::parse_all(LINES_3.iter().map(|s| s.to_string())).unwrap();
+
+        assert_eq!(combine_counters(&modules), Some(Ok(35)));
+        assert_eq!(press_until_rx(&modules, 100), Ok(35));
+        assert_eq!(press_until_rx(&modules, 34), Err(SolveError::UnreachableTarget("a low pulse to `rx` within 34 presses".to_string())));
+    }
+
+    #[test]
+    fn fallback() {
+        let modules = Day20::parse("broadcaster -> a, b\n%a -> fin\n%b -> c\n%c -> fin\n&fin -> rx").unwrap();
+
+        assert_eq!(combine_counters(&modules), None);
+        assert_eq!(Day20::part2(&modules), Ok(3));
+
+        let modules = Day20::parse("broadcaster -> a\n%a -> b\n%b -> rx").unwrap();
+
+        assert_eq!(combine_counters(&modules), None);
+        assert_eq!(Day20::part2(&modules), Ok(4));
+    }
+
+    #[derive(Debug, Clone)]
+    struct Inverter;
+
+    impl PulseModule for Inverter {
+        fn receive(&mut self, _from: &str, pulse: Pulse) -> Option<Pulse> {
+            Some(if pulse == Pulse::High { Pulse::Low } else { Pulse::High })
+        }
+    }
+
+    #[derive(Debug, Clone, Default)]
+    struct Recorder {
+        pulses: Vec<Pulse>,
+    }
+
+    impl PulseModule for Recorder {
+        fn receive(&mut self, _from: &str, pulse: Pulse) -> Option<Pulse> {
+            self.pulses.push(pulse);
+            None
+        }
+    }
+
+    #[test]
+    fn custom_modules() {
+        let registry = Registry::default().with('!', || Inverter).with('@', Recorder::default);
+        let lines = ["broadcaster -> a", "%a -> not", "!not -> out", "@out -> "];
+        let modules = Module::parse_all_with(lines.iter().map(|s| s.to_string()), &registry).unwrap();
+        let mut simulator = Simulator::new(modules);
+
+        simulator.press_n(3);
+
+        let recorder = simulator.module("out").and_then(|module| module.downcast_ref::<Recorder>()).unwrap();
+
+        assert_eq!(recorder.pulses, vec! [Pulse::Low, Pulse::High, Pulse::Low]);
+        assert!(simulator.module("not").unwrap().downcast_ref::<Recorder>().is_none());
+
+        let err = Module::parse_all(lines.iter().map(|s| s.to_string())).unwrap_err();
+
+        assert_eq!((err.line(), err.text(), err.expected()), (3, "!not", "`broadcaster`, `%<name>` or `&<name>`"));
+        assert_eq!(Registry::new().expected(), "`broadcaster`");
+    }
+
+    #[test]
+    fn trace() {
+        let modules = Module::parse_all(LINES_2.iter().map(|s| s.to_string())).unwrap();
+        let mut simulator = Simulator::new(modules);
+        let trace = simulator.press();
+
+        assert_eq!(trace.len(), 8);
+        assert_eq!(trace[0], PulseEvent { press: 1, from: "button".to_string(), to: "broadcaster".to_string(), pulse: Pulse::Low });
+        assert_eq!(trace[7], PulseEvent { press: 1, from: "con".to_string(), to: "output".to_string(), pulse: Pulse::Low });
+        assert_eq!(simulator.press_n(3).iter().map(|event| event.press).collect::<BTreeSet<_>>(), BTreeSet::from([2, 3, 4]));
+        assert_eq!(simulator.presses(), 4);
+    }
+
+    #[test]
+    fn vcd() {
+        let modules = Module::parse_all(LINES_1.iter().map(|s| s.to_string())).unwrap();
+        let mut vcd = vec! [];
+
+        write_vcd(&Simulator::new(modules).press_n(2), &mut vcd).unwrap();
+
+        let vcd = String::from_utf8(vcd).unwrap();
+        let (header, changes) = vcd.split_once("$enddefinitions $end\n").unwrap();
+        let (initial, changes) = changes.split_once("$end\n").unwrap();
+
+        assert_eq!(header.lines().collect::<Vec<_>>(), vec! [
+            "$timescale 1ns $end",
+            "$scope module network $end",
+            "$var integer 32 ! press $end",
+            "$var wire 1 \" a $end",
+            "$var wire 1 # b $end",
+            "$var wire 1 $ broadcaster $end",
+            "$var wire 1 % button $end",
+            "$var wire 1 & c $end",
+            "$var wire 1 ' inv $end",
+            "$upscope $end",
+        ]);
+        assert_eq!(initial, "$dumpvars\nb0 !\n0\"\n0#\n0$\n0%\n0&\n0'\n");
+
+        let timestamps = changes.lines().filter_map(|line| line.strip_prefix('#')).map(|time| time.parse::<usize>().unwrap());
+
+        assert!(timestamps.eq(0..=24));
+        assert!(changes.starts_with("#0\nb1 !\n0%\n#1\n0$\n"));
+        assert!(changes.contains("#12\nb10 !\n0%\n"));
+
+        let wires = header.lines().filter_map(|line| line.strip_prefix("$var wire 1 ")).map(|line| &line[..1]).collect::<Vec<_>>();
+
+        assert!(changes.lines().filter(|line| line.starts_with(['0', '1'])).all(|line| wires.contains(&&line[1..])));
+    }
+
+    #[test]
+    fn dot() {
+        let modules = Module::parse_all(LINES_2.iter().map(|s| s.to_string())).unwrap();
+        let groups = sub_counters(&modules, &["inv".to_string(), "b".to_string()]);
+        let dot = to_dot(&modules, &groups);
+
+        assert_eq!(groups, vec! [BTreeSet::new(), BTreeSet::from(["b"])]);
+        assert!(dot.starts_with("digraph modules {\n"));
+        assert!(dot.contains("    \"broadcaster\" [shape=doubleoctagon];\n"));
+        assert!(dot.contains("    \"b\" [shape=box, style=filled, fillcolor=lightpink];\n"));
+        assert!(dot.contains("    \"con\" [shape=diamond];\n"));
+        assert!(dot.contains("    \"output\" [shape=plaintext];\n"));
+        assert!(dot.contains("    \"a\" -> \"inv\";\n    \"a\" -> \"con\";\n"));
+        assert!(dot.ends_with("}\n"));
+    }
+
+    #[test]
+    fn periods() {
+        let modules = Module::parse_all(LINES_2.iter().map(|s| s.to_string())).unwrap();
+        let periods = Simulator::new(modules).periods(100);
+
+        assert_eq!(periods["broadcaster"], Period { offset: 0, length: 1 });
+        assert_eq!(periods["a"], Period { offset: 0, length: 2 });
+        assert_eq!(periods["con"], Period { offset: 0, length: 4 });
+        assert_eq!(vcd_identifier(93), "~");
+        assert_eq!(vcd_identifier(94), "!!");
+    }
+}
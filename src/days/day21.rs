@@ -1,37 +1,33 @@
-use std::{collections::{VecDeque, HashSet}, io::{self, BufRead}};
+use std::collections::{VecDeque, HashSet};
 
-use aoc_2023::prelude::*;
+use crate::{prelude::*, Solution};
 
-struct Garden {
-    data: CooMatrix2D<char>,
+pub struct Garden {
+    data: Grid<char>,
 }
 
 impl Garden {
-    fn parse(lines: impl Iterator<Item=String>) -> Self {
-        Self {
-            data: lines.enumerate()
-                .flat_map(|(row, line)| line.chars().enumerate().map(move |(col, ch)| ((row as i32, col as i32), ch)).collect::<Vec<_>>())
-                .collect()
+    fn parse<'a>(lines: impl IntoIterator<Item=&'a str>) -> Result<Self, ParseError> {
+        let data = Grid::parse_chars(lines, ".#S")?;
+
+        if data.position(|&ch| ch == 'S').is_none() {
+            return Err(ParseError::end_of_input("a starting point `S`").at_line(data.rows()));
         }
+
+        Ok(Self { data })
     }
 
-    fn starting_point(&self) -> (i32, i32) {
-        self.data.iter()
-            .find_map(|(key, &ch)| if ch == 'S' { Some(key) } else { None })
-            .expect("no starting point found")
+    fn starting_point(&self) -> Point2<i32> {
+        self.data.position(|&ch| ch == 'S').unwrap()
     }
 
-    fn neighbours(&self, point: (i32, i32)) -> impl Iterator<Item=(i32, i32)> + '_ {
-        debug_assert_eq!(self.data.min(), (0, 0));
+    fn neighbours(&self, point: Point2<i32>) -> impl Iterator<Item=Point2<i32>> + '_ {
+        let (rows, cols) = (self.data.rows() as i32, self.data.cols() as i32);
 
-        [
-            (point.0 - 1, point.1),
-            (point.0 + 1, point.1),
-            (point.0, point.1 - 1),
-            (point.0, point.1 + 1)
-        ].into_iter()
-            .filter(|&neighbour| {
-                let new_neighbour = (neighbour.0.rem_euclid(self.data.max().0), neighbour.1.rem_euclid(self.data.max().1));
+        Direction::ALL.into_iter()
+            .map(move |direction| point + direction.delta())
+            .filter(move |&neighbour| {
+                let new_neighbour = Point2::new(neighbour.x.rem_euclid(cols), neighbour.y.rem_euclid(rows));
 
                 match self.data.get(new_neighbour) {
                     Some(&ch) => ch != '#',
@@ -40,7 +36,7 @@ impl Garden {
             })
     }
 
-    fn reachable_(&self, from: (i32, i32), n: usize) -> usize {
+    fn reachable_(&self, from: Point2<i32>, n: usize) -> usize {
         let mut to_visit = VecDeque::from([ from ]);
         let mut all_neighbours = VecDeque::new();
         let mut visited = HashSet::new();
@@ -88,7 +84,7 @@ impl Garden {
     fn polynomical_reachable(&self, n: usize) -> usize {
         assert_eq!((n - 65) % 131, 0);
 
-        let mut xs = vec! [ self.reachable(1 * 131 + 65) ];
+        let mut xs = vec! [ self.reachable(131 + 65) ];
         let mut correct = false;
 
         while xs.len() < (n - 65) / 131 {
@@ -108,13 +104,24 @@ impl Garden {
     }
 }
 
-fn main() {
-    let stdin = io::stdin().lock();
-    let lines = stdin.lines().filter_map(Result::ok).collect::<Vec<_>>();
-    let garden = Garden::parse(lines.into_iter());
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Garden;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Garden::parse(input.lines())
+    }
+
+    fn part1(garden: &Self::Input) -> Result<usize, SolveError> {
+        Ok(garden.reachable(64))
+    }
 
-    println!("{}", garden.reachable(64));
-    println!("{}", garden.polynomical_reachable(26501365));
+    fn part2(garden: &Self::Input) -> Result<usize, SolveError> {
+        Ok(garden.polynomical_reachable(26501365))
+    }
 }
 
 #[cfg(test)]
@@ -137,7 +144,7 @@ mod tests {
 
     #[test]
     fn _01() {
-        let garden = Garden::parse(LINES.iter().map(|s| s.to_string()));
+        let garden = Garden::parse(LINES).unwrap();
 
         assert_eq!(garden.reachable(6), 16);
     }
@@ -145,7 +152,7 @@ mod tests {
     #[test]
     #[ignore = "too slow"]
     fn _02() {
-        let garden = Garden::parse(LINES.iter().map(|s| s.to_string()));
+        let garden = Garden::parse(LINES).unwrap();
 
         assert_eq!(garden.reachable(6), 16);
         assert_eq!(garden.reachable(10), 50);
@@ -1,29 +1,38 @@
-use std::{collections::HashSet, io::{self, BufRead}};
+use std::collections::HashSet;
 
-use sscanf::scanf;
+use crate::{prelude::*, Solution};
 
 #[derive(Clone, Debug)]
-struct Brick {
+pub struct Brick {
     id: u32,
     from: (i32, i32, i32),
     to: (i32, i32, i32),
 }
 
 impl Brick {
-    fn parse_all(lines: impl Iterator<Item=String>) -> Vec<Self> {
-        lines.enumerate().filter_map(|(id, line)| {
-            let (x1, y1, z1, x2, y2, z2) = scanf!(line, "{i32},{i32},{i32}~{i32},{i32},{i32}").ok()?;
+    fn parse(id: u32, line: &str) -> Result<Self, ParseError> {
+        let (from, to) = line.split_once('~').ok_or_else(|| ParseError::new(line, line, "`<x>,<y>,<z>~<x>,<y>,<z>`"))?;
+        let coordinates = |s: &str| -> Result<(i32, i32, i32), ParseError> {
+            let numbers = s.split(',').map(|n| parse_token(line, n, "a coordinate")).collect::<Result<Vec<_>, _>>()?;
+
+            match numbers[..] {
+                [x, y, z] => Ok((x, y, z)),
+                _ => Err(ParseError::new(line, s, "`<x>,<y>,<z>`")),
+            }
+        };
+        let (from_, to_) = (coordinates(from)?, coordinates(to)?);
+
+        if from_.0 > to_.0 || from_.1 > to_.1 || from_.2 > to_.2 {
+            return Err(ParseError::new(line, to, format!("a corner no smaller than `{}`", from)));
+        }
 
-            assert!(x1 <= x2);
-            assert!(y1 <= y2);
-            assert!(z1 <= z2);
+        Ok(Self { id, from: from_, to: to_ })
+    }
 
-            Some(Self {
-                id: id as u32,
-                from: (x1, y1, z1),
-                to: (x2, y2, z2),
-            })
-        }).collect()
+    fn parse_all(lines: impl Iterator<Item=String>) -> Result<Vec<Self>, ParseError> {
+        lines.enumerate()
+            .map(|(id, line)| Self::parse(id as u32, &line).map_err(|err| err.at_line(id + 1)))
+            .collect()
     }
 
     fn intersects(&self, other_brick: &Self) -> bool {
@@ -105,14 +114,24 @@ impl Stack {
     }
 }
 
-fn main() {
-    let stdin = io::stdin().lock();
-    let lines = stdin.lines().filter_map(Result::ok).collect::<Vec<_>>();
-    let bricks = Brick::parse_all(lines.into_iter());
-    let stack = Stack::new(&bricks).collapse().0;
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Vec<Brick>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Brick::parse_all(input.lines().map(str::to_string))
+    }
+
+    fn part1(bricks: &Self::Input) -> Result<usize, SolveError> {
+        Ok(Stack::new(bricks).collapse().0.safe_disintegrate())
+    }
 
-    println!("{}", stack.safe_disintegrate());
-    println!("{}", stack.unsafe_disintegrate());
+    fn part2(bricks: &Self::Input) -> Result<usize, SolveError> {
+        Ok(Stack::new(bricks).collapse().0.unsafe_disintegrate())
+    }
 }
 
 #[cfg(test)]
@@ -131,7 +150,7 @@ mod tests {
 
     #[test]
     fn _01() {
-        let bricks = Brick::parse_all(LINES.iter().map(|s| s.to_string()));
+        let bricks = Brick::parse_all(LINES.iter().map(|s| s.to_string())).unwrap();
         let stack = Stack::new(&bricks).collapse().0;
 
         assert_eq!(stack.bricks.len(), 7);
@@ -140,9 +159,16 @@ mod tests {
 
     #[test]
     fn _02() {
-        let bricks = Brick::parse_all(LINES.iter().map(|s| s.to_string()));
+        let bricks = Brick::parse_all(LINES.iter().map(|s| s.to_string())).unwrap();
         let stack = Stack::new(&bricks).collapse().0;
 
         assert_eq!(stack.unsafe_disintegrate(), 7, "{:?}", bricks);
     }
+
+    #[test]
+    fn parse_error() {
+        let err = Day22::parse("1,0,1~1,2,1\n0,0,2~2,0,1").err().unwrap();
+
+        assert_eq!((err.line(), err.column(), err.text()), (2, 7, "2,0,1"));
+    }
 }
@@ -0,0 +1,201 @@
+use std::collections::{HashSet, HashMap, BTreeSet};
+
+use crate::{prelude::*, Solution};
+
+type LongestPaths = HashMap<(Point2<i32>, BTreeSet<Point2<i32>>), Option<usize>>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Slope {
+    Up,
+    Down,
+    None,
+}
+
+pub struct Hike {
+    tiles: Grid<char>,
+}
+
+impl Hike {
+    fn parse<'a>(lines: impl IntoIterator<Item=&'a str>) -> Result<Self, ParseError> {
+        Ok(Self { tiles: Grid::parse_chars(lines, "#.<>^v")? })
+    }
+
+    fn open_tile_in_row(&self, row: usize) -> Option<Point2<i32>> {
+        self.tiles.row(row).iter()
+            .position(|&ch| ch == '.')
+            .map(|col| Point2::new(col as i32, row as i32))
+    }
+
+    fn starting_point(&self) -> Option<Point2<i32>> {
+        self.open_tile_in_row(0)
+    }
+
+    fn end_point(&self) -> Option<Point2<i32>> {
+        self.open_tile_in_row(self.tiles.rows().checked_sub(1)?)
+    }
+
+    fn slope(&self, from: Point2<i32>, to: Point2<i32>) -> Slope {
+        let downhill = match self.tiles[to] {
+            '^' => Direction::North,
+            'v' => Direction::South,
+            '<' => Direction::West,
+            '>' => Direction::East,
+            _ => return Slope::None,
+        };
+
+        if to - from == downhill.delta() {
+            Slope::Down
+        } else if to - from == downhill.opposite().delta() {
+            Slope::Up
+        } else {
+            Slope::None
+        }
+    }
+
+    fn neighbours(&self, point: Point2<i32>) -> impl Iterator<Item=Point2<i32>> + '_ {
+        self.tiles.neighbours4(point)
+            .filter(|&(_, &ch)| ch != '#')
+            .map(|(pos, _)| pos)
+    }
+
+    fn is_downhill(&self, from: Point2<i32>, to: Point2<i32>) -> bool {
+        matches!(self.slope(from, to), Slope::Down | Slope::None)
+    }
+
+    fn reachable(
+        &self,
+        from: Point2<i32>,
+        is_valid: impl Fn(Point2<i32>, Point2<i32>) -> bool + Clone,
+        visited: &HashSet<Point2<i32>>
+    ) -> BTreeSet<Point2<i32>>
+    {
+        debug_assert!(!visited.contains(&from));
+
+        let mut reachable = BTreeSet::new();
+        let mut to_visit = vec! [from];
+
+        while let Some(point) = to_visit.pop() {
+            if !visited.contains(&point) && reachable.insert(point) {
+                to_visit.extend(self.neighbours(point).filter(|&to| is_valid(point, to)));
+            }
+        }
+
+        reachable
+    }
+
+    fn longest_path_(
+        &self,
+        starting_point: Point2<i32>,
+        end_point: Point2<i32>,
+        is_valid: impl Fn(Point2<i32>, Point2<i32>) -> bool + Clone,
+        mut visited: HashSet<Point2<i32>>,
+        so_far: &mut LongestPaths,
+    ) -> Option<usize>
+    {
+        let reachable = self.reachable(starting_point, is_valid.clone(), &visited);
+        let mut current_point = starting_point;
+
+        if !reachable.contains(&end_point) {
+            return None;
+        } else if let Some(&max_distance) = so_far.get(&(starting_point, reachable.clone())) {
+            return max_distance;
+        }
+
+        let mut count = 0;
+
+        loop {
+            if current_point == end_point {
+                so_far.insert((starting_point, reachable), Some(count));
+                return Some(count);
+            } else if !visited.insert(current_point) {
+                unreachable!("loop invariant");
+            }
+
+            let neighbours = self.neighbours(current_point)
+                .filter(|&point| !visited.contains(&point))
+                .filter(|&point| is_valid(current_point, point))
+                .collect::<Vec<_>>();
+
+            if neighbours.len() == 1 {
+                current_point = neighbours[0];
+                count += 1;
+            } else {
+                let max_distance = neighbours.into_iter()
+                    .filter_map(|point| self.longest_path_(point, end_point, is_valid.clone(), visited.clone(), so_far))
+                    .max().map(|max_distance| max_distance + count + 1);
+
+                so_far.insert((starting_point, reachable), max_distance);
+                return max_distance;
+            }
+        }
+    }
+
+    fn longest_path(&self, is_valid: impl Fn(Point2<i32>, Point2<i32>) -> bool + Clone) -> Result<usize, SolveError> {
+        self.starting_point().zip(self.end_point())
+            .and_then(|(starting_point, end_point)| self.longest_path_(starting_point, end_point, is_valid, HashSet::new(), &mut HashMap::new()))
+            .ok_or_else(|| SolveError::UnreachableTarget("end of the trail".to_string()))
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = Hike;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Hike::parse(input.lines())
+    }
+
+    fn part1(hike: &Self::Input) -> Result<usize, SolveError> {
+        hike.longest_path(|from, to| hike.is_downhill(from, to))
+    }
+
+    fn part2(hike: &Self::Input) -> Result<usize, SolveError> {
+        hike.longest_path(|_, _| true) // not 4838
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINES: [&str; 23] = [
+        "#.#####################",
+        "#.......#########...###",
+        "#######.#########.#.###",
+        "###.....#.>.>.###.#.###",
+        "###v#####.#v#.###.#.###",
+        "###.>...#.#.#.....#...#",
+        "###v###.#.#.#########.#",
+        "###...#.#.#.......#...#",
+        "#####.#.#.#######.#.###",
+        "#.....#.#.#.......#...#",
+        "#.#####.#.#.#########v#",
+        "#.#...#...#...###...>.#",
+        "#.#.#v#######v###.###v#",
+        "#...#.>.#...>.>.#.###.#",
+        "#####v#.#.###v#.#.###.#",
+        "#.....#...#...#.#.#...#",
+        "#.#########.###.#.#.###",
+        "#...###...#...#...#.###",
+        "###.###.#.###v#####v###",
+        "#...#...#.#.>.>.#.>.###",
+        "#.###.###.#.###.#.#v###",
+        "#.....###...###...#...#",
+        "#####################.#",
+    ];
+
+    #[test]
+    fn _01() {
+        let hike = Hike::parse(LINES).unwrap();
+
+        assert_eq!(hike.longest_path(|from, to| hike.is_downhill(from, to)), Ok(94));
+    }
+
+    #[test]
+    fn _02() {
+        assert_eq!(Hike::parse(LINES).unwrap().longest_path(|_, _| true), Ok(154));
+    }
+}
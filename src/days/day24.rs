@@ -0,0 +1,577 @@
+use std::{str::FromStr, fmt::{self, Display}, ops::RangeInclusive};
+
+#[cfg(feature = "smt")]
+use z3::{Config, Context, SatResult, Solver, ast::{Ast, Int}};
+
+use crate::{prelude::*, Solution};
+
+/// The area that part 1 looks for crossing paths in, unless given another.
+pub const TEST_AREA: RangeInclusive<i64> = 200000000000000..=400000000000000;
+
+#[derive(Debug, Clone, Copy)]
+struct Hail {
+    point: [i64; 3],
+    velocity: [i64; 3],
+}
+
+impl Display for Hail {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [x, y, z] = self.point;
+        let [dx, dy, dz] = self.velocity;
+
+        write!(f, "({}, {}, {}) @ ({}, {}, {})", x, y, z, dx, dy, dz)
+    }
+}
+
+impl FromStr for Hail {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (point, velocity) = s.split_once('@').ok_or_else(|| ParseError::new(s, s, "`<x>, <y>, <z> @ <dx>, <dy>, <dz>`"))?;
+        let triple = |part: &str| -> Result<[i64; 3], ParseError> {
+            let numbers = part.split(',').map(|n| parse_token(s, n.trim(), "an integer")).collect::<Result<Vec<_>, _>>()?;
+
+            numbers.try_into().map_err(|_| ParseError::new(s, part.trim(), "three numbers"))
+        };
+
+        Ok(Hail {
+            point: triple(point)?,
+            velocity: triple(velocity)?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Crossing {
+    At { point: [Rational; 2], times: [Rational; 2] },
+    Parallel,
+    Coincident,
+}
+
+type Interval = (Option<Rational>, Option<Rational>);
+
+fn intersect((a_lower, a_upper): Interval, (b_lower, b_upper): Interval) -> Interval {
+    (a_lower.max(b_lower), match (a_upper, b_upper) {
+        (Some(a), Some(b)) => Some(a.min(b)),
+        (a, b) => a.or(b),
+    })
+}
+
+fn is_empty((lower, upper): Interval) -> bool {
+    matches!((lower, upper), (Some(lower), Some(upper)) if lower > upper)
+}
+
+fn cross([ax, ay]: [i128; 2], [bx, by]: [i128; 2]) -> i128 {
+    ax * by - ay * bx
+}
+
+fn dot([ax, ay]: [i128; 2], [bx, by]: [i128; 2]) -> i128 {
+    ax * bx + ay * by
+}
+
+impl Hail {
+    fn point2d(&self) -> [i128; 2] {
+        [self.point[0] as i128, self.point[1] as i128]
+    }
+
+    fn velocity2d(&self) -> [i128; 2] {
+        [self.velocity[0] as i128, self.velocity[1] as i128]
+    }
+
+    fn crossing2d(&self, other: &Self) -> Crossing {
+        let (a, b) = (self.velocity2d(), other.velocity2d());
+        let [dx, dy] = [other.point[0] as i128 - self.point[0] as i128, other.point[1] as i128 - self.point[1] as i128];
+        let determinant = cross(a, b);
+
+        if determinant == 0 {
+            return if cross([dx, dy], a) == 0 && cross([dx, dy], b) == 0 {
+                Crossing::Coincident
+            } else {
+                Crossing::Parallel
+            };
+        }
+
+        let t = Rational::new(cross([dx, dy], b), determinant);
+        let u = Rational::new(cross([dx, dy], a), determinant);
+        let [x, y] = self.point2d();
+        let point = [Rational::from(x) + Rational::from(a[0]) * t, Rational::from(y) + Rational::from(a[1]) * t];
+
+        Crossing::At { point, times: [t, u] }
+    }
+
+    fn within(&self, area: &RangeInclusive<i64>) -> Interval {
+        let range = (Rational::from(*area.start()), Rational::from(*area.end()));
+
+        self.point2d().into_iter().zip(self.velocity2d()).fold((None, None), |interval, (start, step)| {
+            let start = Rational::from(start);
+            let axis = match step.signum() {
+                0 if (range.0..=range.1).contains(&start) => (None, None),
+                0 => (Some(Rational::ONE), Some(Rational::ZERO)),
+                signum => {
+                    let (low, high) = ((range.0 - start) / Rational::from(step), (range.1 - start) / Rational::from(step));
+
+                    if signum > 0 { (Some(low), Some(high)) } else { (Some(high), Some(low)) }
+                },
+            };
+
+            intersect(interval, axis)
+        })
+    }
+
+    fn meets_within(&self, other: &Self, area: &RangeInclusive<i64>) -> bool {
+        match self.crossing2d(other) {
+            Crossing::At { point, times } => {
+                let area = Rational::from(*area.start())..=Rational::from(*area.end());
+
+                times.iter().all(|t| !t.is_negative()) && point.iter().all(|p| area.contains(p))
+            },
+            Crossing::Parallel => false,
+            Crossing::Coincident => {
+                let (a, b) = if self.velocity2d() == [0, 0] { (other, self) } else { (self, other) };
+                let direction = a.velocity2d();
+                let scale = dot(direction, direction);
+
+                if scale == 0 {
+                    return a.point2d() == b.point2d() && !is_empty(a.within(area));
+                }
+
+                let offset = [b.point[0] as i128 - a.point[0] as i128, b.point[1] as i128 - a.point[1] as i128];
+                let start = Rational::new(dot(offset, direction), scale);
+                let other_path = match dot(b.velocity2d(), direction).signum() {
+                    0 => (Some(start), Some(start)),
+                    1 => (Some(start), None),
+                    _ => (None, Some(start)),
+                };
+
+                !is_empty(intersect(intersect(a.within(area), (Some(Rational::ZERO), None)), other_path))
+            },
+        }
+    }
+}
+
+type Vec3 = [Rational; 3];
+
+fn to_vec3(values: [i64; 3]) -> Vec3 {
+    values.map(Rational::from)
+}
+
+fn add3(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn sub3(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn scale3(a: Vec3, s: Rational) -> Vec3 {
+    a.map(|value| value * s)
+}
+
+fn cross3(a: Vec3, b: Vec3) -> Vec3 {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+fn table<const N: usize>(header: [&str; N], rows: impl IntoIterator<Item=[String; N]>) -> String {
+    let rows = std::iter::once(header.map(str::to_string)).chain(rows).collect::<Vec<_>>();
+    let widths = (0..N).map(|i| rows.iter().map(|row| row[i].len()).max().unwrap_or(0)).collect::<Vec<_>>();
+
+    rows.iter()
+        .map(|row| row.iter().zip(&widths).map(|(cell, &width)| format!("{:>width$}", cell)).collect::<Vec<_>>().join("  "))
+        .map(|line| line + "\n")
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PathCrossing {
+    pub stones: [usize; 2],
+    pub crossing: Crossing,
+}
+
+/// Renders crossings as a table, numbering the stones by their line in the input.
+pub fn crossings_table(crossings: &[PathCrossing]) -> String {
+    table(["a", "b", "x", "y", "time a", "time b"], crossings.iter().map(|&PathCrossing { stones: [a, b], crossing }| {
+        let [x, y, time_a, time_b] = match crossing {
+            Crossing::At { point: [x, y], times: [t, u] } => [x, y, t, u].map(|value| value.to_string()),
+            _ => ["-", "-", "same path", "same path"].map(str::to_string),
+        };
+
+        [(a + 1).to_string(), (b + 1).to_string(), x, y, time_a, time_b]
+    }))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rock {
+    pub position: [i128; 3],
+    pub velocity: [i128; 3],
+}
+
+impl Display for Rock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let [x, y, z] = self.position;
+        let [dx, dy, dz] = self.velocity;
+
+        write!(f, "({}, {}, {}) @ ({}, {}, {})", x, y, z, dx, dy, dz)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Impact {
+    pub time: Rational,
+    pub stone: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Throw {
+    pub rock: Rock,
+    pub impacts: Vec<Impact>,
+}
+
+impl Display for Throw {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let position = |time: Rational| [0, 1, 2].map(|i| Rational::from(self.rock.position[i]) + Rational::from(self.rock.velocity[i]) * time);
+
+        writeln!(f, "rock {}", self.rock)?;
+        writeln!(f)?;
+        write!(f, "{}", table(["time", "stone", "x", "y", "z"], self.impacts.iter().map(|impact| {
+            let [x, y, z] = position(impact.time).map(|value| value.to_string());
+
+            [impact.time.to_string(), (impact.stone + 1).to_string(), x, y, z]
+        })))
+    }
+}
+
+impl Rock {
+    fn hits(&self, hail: &Hail) -> Option<Rational> {
+        let offset = [0, 1, 2].map(|i| hail.point[i] as i128 - self.position[i]);
+        let closing = [0, 1, 2].map(|i| self.velocity[i] - hail.velocity[i] as i128);
+        let parallel = (0..3).all(|i| offset[(i + 1) % 3] * closing[(i + 2) % 3] == offset[(i + 2) % 3] * closing[(i + 1) % 3]);
+
+        if !parallel {
+            return None;
+        }
+
+        match (0..3).find(|&i| closing[i] != 0) {
+            Some(i) => Some(Rational::new(offset[i], closing[i])).filter(|time| !time.is_negative()),
+            None => (offset == [0, 0, 0]).then_some(Rational::ZERO),
+        }
+    }
+}
+
+pub struct Storm {
+    hail: Vec<Hail>,
+    area: RangeInclusive<i64>,
+}
+
+impl Storm {
+    fn new(hail: &[Hail]) -> Self {
+        Self {
+            hail: hail.to_vec(),
+            area: TEST_AREA,
+        }
+    }
+
+    pub fn with_area(self, area: RangeInclusive<i64>) -> Self {
+        Self { area, ..self }
+    }
+
+    pub fn crossings2d(&self) -> Vec<PathCrossing> {
+        let mut crossings = vec! [];
+
+        for i in 0..self.hail.len() {
+            for j in i + 1..self.hail.len() {
+                if self.hail[i].meets_within(&self.hail[j], &self.area) {
+                    crossings.push(PathCrossing { stones: [i, j], crossing: self.hail[i].crossing2d(&self.hail[j]) });
+                }
+            }
+        }
+
+        crossings
+    }
+
+    fn num_intersects2d(&self) -> usize {
+        self.crossings2d().len()
+    }
+
+    fn throw_through(first: &Hail, a: &Hail, b: &Hail) -> Option<[Vec3; 2]> {
+        let relative = |hail: &Hail| (sub3(to_vec3(hail.point), to_vec3(first.point)), sub3(to_vec3(hail.velocity), to_vec3(first.velocity)));
+        let paths = [relative(a), relative(b)];
+        let mut normals = paths.iter().map(|&(point, velocity)| cross3(point, velocity).to_vec()).collect::<Vec<_>>();
+        let pivots = row_reduce(&mut normals);
+
+        if pivots.len() != 2 {
+            return None;
+        }
+
+        let free = (0..3).find(|column| !pivots.contains(column)).unwrap();
+        let mut direction = [Rational::ZERO; 3];
+
+        direction[free] = Rational::ONE;
+
+        for (row, &column) in pivots.iter().enumerate() {
+            direction[column] = -normals[row][free];
+        }
+
+        let mut hits = vec! [];
+
+        for (point, velocity) in paths {
+            let mut system = (0..3).map(|i| vec! [velocity[i], -direction[i], -point[i]]).collect::<Vec<_>>();
+
+            if row_reduce(&mut system) != [0, 1] {
+                return None;
+            }
+
+            let time = system[0][2];
+
+            hits.push((time, add3(point, scale3(velocity, time))));
+        }
+
+        let [(t1, p1), (t2, p2)] = hits[..] else { unreachable!() };
+
+        if t1 == t2 {
+            return None;
+        }
+
+        let velocity = scale3(sub3(p2, p1), Rational::ONE / (t2 - t1));
+        let position = sub3(p1, scale3(velocity, t1));
+
+        Some([add3(position, to_vec3(first.point)), add3(velocity, to_vec3(first.velocity))])
+    }
+
+    pub fn throw(&self) -> Result<Throw, SolveError> {
+        let unsatisfiable = || SolveError::UnsatisfiableSystem("rock trajectory".to_string());
+        let [first, rest @ ..] = &self.hail[..] else {
+            return Err(unsatisfiable());
+        };
+
+        let [position, velocity] = rest.iter().enumerate()
+            .flat_map(|(i, a)| rest[i + 1..].iter().map(move |b| (a, b)))
+            .find_map(|(a, b)| Self::throw_through(first, a, b))
+            .ok_or_else(unsatisfiable)?;
+        let integers = |values: Vec3| values.iter().map(Rational::to_integer).collect::<Option<Vec<_>>>().map(|values| [values[0], values[1], values[2]]);
+        let rock = Rock {
+            position: integers(position).ok_or_else(unsatisfiable)?,
+            velocity: integers(velocity).ok_or_else(unsatisfiable)?,
+        };
+
+        let mut impacts = self.hail.iter().enumerate()
+            .map(|(stone, hail)| rock.hits(hail).map(|time| Impact { time, stone }))
+            .collect::<Option<Vec<_>>>()
+            .ok_or_else(unsatisfiable)?;
+
+        impacts.sort_by_key(|impact| (impact.time, impact.stone));
+
+        Ok(Throw { rock, impacts })
+    }
+
+    fn find_ray(&self) -> Result<i64, SolveError> {
+        let throw = self.throw()?;
+
+        Ok(throw.rock.position.iter().sum::<i128>() as i64)
+    }
+
+    /// Like [`Storm::find_ray`], but solved by Z3. Only available with the `smt` feature.
+    #[cfg(not(feature = "smt"))]
+    pub fn find_ray_smt(&self) -> Result<i64, SolveError> {
+        Err(SolveError::MissingFeature("smt".to_string()))
+    }
+
+    #[cfg(feature = "smt")]
+    pub fn find_ray_smt(&self) -> Result<i64, SolveError> {
+        let config = Config::new();
+        let context = Context::new(&config);
+        let solver = Solver::new(&context);
+
+        let x = Int::new_const(&context, "x");
+        let y = Int::new_const(&context, "y");
+        let z = Int::new_const(&context, "z");
+        let vx = Int::new_const(&context, "vx");
+        let vy = Int::new_const(&context, "vy");
+        let vz = Int::new_const(&context, "vz");
+
+        for (i, hail) in self.hail.iter().enumerate() {
+            let x_n = Int::from_i64(&context, hail.point[0]);
+            let y_n = Int::from_i64(&context, hail.point[1]);
+            let z_n = Int::from_i64(&context, hail.point[2]);
+            let vx_n = Int::from_i64(&context, hail.velocity[0]);
+            let vy_n = Int::from_i64(&context, hail.velocity[1]);
+            let vz_n = Int::from_i64(&context, hail.velocity[2]);
+            let t_n = Int::fresh_const(&context, &format!("t_{}", i));
+
+            solver.assert(&t_n.ge(&Int::from_i64(&context, 0)));
+            solver.assert(&(&x + &vx * &t_n)._eq(&(&x_n + &vx_n * &t_n)));
+            solver.assert(&(&y + &vy * &t_n)._eq(&(&y_n + &vy_n * &t_n)));
+            solver.assert(&(&z + &vz * &t_n)._eq(&(&z_n + &vz_n * &t_n)));
+        }
+
+        let unsatisfiable = || SolveError::UnsatisfiableSystem("rock trajectory".to_string());
+
+        if solver.check() != SatResult::Sat {
+            return Err(unsatisfiable());
+        }
+
+        let model = solver.get_model().ok_or_else(unsatisfiable)?;
+        let value = |v: &Int| model.get_const_interp(v).and_then(|v| v.as_i64()).ok_or_else(unsatisfiable);
+
+        Ok(value(&x)? + value(&y)? + value(&z)?)
+    }
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Storm;
+    type Part1 = usize;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let stones = parse_lines(input, str::parse::<Hail>)?;
+
+        Ok(Storm::new(&stones))
+    }
+
+    fn part1(storm: &Self::Input) -> Result<usize, SolveError> {
+        Ok(storm.num_intersects2d())
+    }
+
+    fn part2(storm: &Self::Input) -> Result<i64, SolveError> {
+        storm.find_ray()
+    }
+
+    fn render(storm: &Self::Input, part: u32) -> Option<Result<String, SolveError>> {
+        match part {
+            1 => Some(Ok(crossings_table(&storm.crossings2d()))),
+            2 => Some(storm.throw().map(|throw| throw.to_string())),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINES: [&str; 5] = [
+        "19, 13, 30 @ -2, 1, -2",
+        "18, 19, 22 @ -1, -1, -2",
+        "20, 25, 34 @ -2, -2, -4",
+        "12, 31, 28 @ -1, -2, -1",
+        "20, 19, 15 @ 1, -5, -3",
+    ];
+
+    #[test]
+    fn _01() {
+        let stones = LINES.iter().map(|line| line.parse::<Hail>().unwrap()).collect::<Vec<_>>();
+        let storm = Storm::new(&stones).with_area(7..=27);
+
+        assert_eq!(storm.num_intersects2d(), 2);
+        assert_eq!(Storm::new(&stones).num_intersects2d(), 0);
+    }
+
+    #[test]
+    fn crossings() {
+        let stones = LINES.iter().map(|line| line.parse::<Hail>().unwrap()).collect::<Vec<_>>();
+        let at = |x: (i128, i128), y: (i128, i128), t: (i128, i128), u: (i128, i128)| Crossing::At {
+            point: [Rational::new(x.0, x.1), Rational::new(y.0, y.1)],
+            times: [Rational::new(t.0, t.1), Rational::new(u.0, u.1)],
+        };
+
+        assert_eq!(stones[0].crossing2d(&stones[1]), at((43, 3), (46, 3), (7, 3), (11, 3)));
+        assert_eq!(stones[0].crossing2d(&stones[4]), at((193, 9), (106, 9), (-11, 9), (13, 9)));
+        assert_eq!(stones[1].crossing2d(&stones[2]), Crossing::Parallel);
+    }
+
+    #[test]
+    fn coincident() {
+        let stone = |line: &str| line.parse::<Hail>().unwrap();
+        let a = stone("0, 0, 0 @ 1, 1, 0");
+
+        assert!(a.meets_within(&stone("-5, -5, 0 @ 2, 2, 0"), &(10..=20)));
+        assert!(!a.meets_within(&stone("-5, -5, 0 @ -2, -2, 0"), &(-10..=20)));
+        assert!(a.meets_within(&stone("30, 30, 5 @ -1, -1, 1"), &(10..=20)));
+        assert!(!a.meets_within(&stone("8, 8, 5 @ -1, -1, 1"), &(10..=20)));
+        assert_eq!(a.crossing2d(&stone("30, 30, 5 @ -1, -1, 1")), Crossing::Coincident);
+        assert!(a.meets_within(&stone("12, 12, 0 @ 0, 0, 3"), &(10..=20)));
+        assert!(!a.meets_within(&stone("-12, -12, 0 @ 0, 0, 3"), &(-20..=20)));
+    }
+
+    #[test]
+    fn large_values() {
+        let a = "200000000000000, 300000000000000, 0 @ 3, -1, 0".parse::<Hail>().unwrap();
+        let b = "400000000000000, 100000000000000, 0 @ -1, 1, 0".parse::<Hail>().unwrap();
+
+        assert_eq!(a.crossing2d(&b), Crossing::At {
+            point: [Rational::from(200000000000000i64), Rational::from(300000000000000i64)],
+            times: [Rational::ZERO, Rational::from(200000000000000i64)],
+        });
+        assert!(a.meets_within(&b, &TEST_AREA));
+        assert!(!a.meets_within(&b, &(200000000000001..=400000000000000)));
+    }
+
+    #[test]
+    fn _02() {
+        let stones = LINES.iter().map(|line| line.parse::<Hail>().unwrap()).collect::<Vec<_>>();
+        let storm = Storm::new(&stones);
+
+        assert_eq!(storm.throw().map(|throw| throw.rock), Ok(Rock { position: [24, 13, 10], velocity: [-3, 1, 2] }));
+        assert_eq!(storm.find_ray(), Ok(47));
+    }
+
+    #[test]
+    fn large_throw() {
+        let rock = Rock { position: [200000000000000, 300000000000000, 250000000000000], velocity: [-123, 45, 67] };
+        let stones = [(100000000000, [12, -34, 56]), (200000000000, [-78, 90, -12]), (300000000000, [34, 56, -78]), (450000000000, [-90, -12, 34])]
+            .map(|(time, velocity): (i64, [i64; 3])| Hail {
+                point: [0, 1, 2].map(|i| rock.position[i] as i64 + (rock.velocity[i] as i64 - velocity[i]) * time),
+                velocity,
+            });
+        let storm = Storm::new(&stones);
+
+        assert_eq!(storm.throw().map(|throw| throw.rock), Ok(rock));
+        assert_eq!(rock.hits(&stones[3]), Some(Rational::from(450000000000i64)));
+    }
+
+    #[test]
+    fn report() {
+        let storm = Day24::parse(&LINES.join("\n")).unwrap().with_area(7..=27);
+        let crossings = storm.crossings2d();
+        let throw = storm.throw().unwrap();
+
+        assert_eq!(crossings.iter().map(|crossing| crossing.stones).collect::<Vec<_>>(), vec! [[0, 1], [0, 2]]);
+        assert_eq!(throw.impacts.iter().map(|impact| (impact.time.to_integer().unwrap(), impact.stone)).collect::<Vec<_>>(), vec! [(1, 4), (3, 1), (4, 2), (5, 0), (6, 3)]);
+        assert_eq!(crossings_table(&crossings), [
+            "a  b     x     y  time a  time b\n",
+            "1  2  43/3  46/3     7/3    11/3\n",
+            "1  3  35/3  50/3    11/3    25/6\n",
+        ].concat());
+        assert_eq!(throw.to_string(), [
+            "rock (24, 13, 10) @ (-3, 1, 2)\n",
+            "\n",
+            "time  stone   x   y   z\n",
+            "   1      5  21  14  12\n",
+            "   3      2  15  16  16\n",
+            "   4      3  12  17  18\n",
+            "   5      1   9  18  20\n",
+            "   6      4   6  19  22\n",
+        ].concat());
+    }
+
+    #[test]
+    fn no_throw() {
+        let stones = ["0, 0, 0 @ 1, 0, 0", "0, 1, 0 @ 0, 1, 0", "0, 0, 1 @ 0, 0, 1", "1, 1, 1 @ 1, 1, 1"].map(|line| line.parse::<Hail>().unwrap());
+
+        assert_eq!(Storm::new(&stones).find_ray(), Err(SolveError::UnsatisfiableSystem("rock trajectory".to_string())));
+        assert_eq!(Storm::new(&stones[..1]).find_ray(), Err(SolveError::UnsatisfiableSystem("rock trajectory".to_string())));
+    }
+
+    #[test]
+    fn smt() {
+        let stones = LINES.iter().map(|line| line.parse::<Hail>().unwrap()).collect::<Vec<_>>();
+        let storm = Storm::new(&stones);
+
+        if cfg!(feature = "smt") {
+            assert_eq!(storm.find_ray_smt(), storm.find_ray());
+        } else {
+            assert_eq!(storm.find_ray_smt(), Err(SolveError::MissingFeature("smt".to_string())));
+        }
+    }
+}
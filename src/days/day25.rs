@@ -0,0 +1,202 @@
+use std::{collections::{HashMap, VecDeque}, str::FromStr};
+
+use crate::{prelude::*, Solution};
+
+struct Component {
+    name: String,
+    connected_to: Vec<String>,
+}
+
+impl FromStr for Component {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, connected_to) = s.split_once(':').ok_or_else(|| ParseError::new(s, s, "`<name>: <components>`"))?;
+        let name = name.trim().to_string();
+        let connected_to = connected_to.split_whitespace().map(|s| s.to_string()).collect();
+
+        Ok(Component {
+            name,
+            connected_to,
+        })
+    }
+}
+
+/// The wiring diagram, with components numbered in the order they first
+/// appear and every wire stored once.
+pub struct Wiring {
+    names: Vec<String>,
+    wires: Vec<(usize, usize)>,
+    /// For every component, its neighbours along with the wire to them.
+    neighbours: Vec<Vec<(usize, usize)>>,
+}
+
+/// A set of wires that splits the components in two.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cut {
+    pub wires: Vec<(usize, usize)>,
+    pub partitions: [Vec<usize>; 2],
+}
+
+impl Wiring {
+    fn new(components: &[Component]) -> Self {
+        let mut names = vec! [];
+        let mut indices = HashMap::new();
+        let mut index = |name: &str| *indices.entry(name.to_string()).or_insert_with(|| {
+            names.push(name.to_string());
+            names.len() - 1
+        });
+        let wires = components.iter()
+            .flat_map(|component| component.connected_to.iter().map(|other| (component.name.as_str(), other.as_str())))
+            .map(|(a, b)| (index(a), index(b)))
+            .collect::<Vec<_>>();
+        let mut neighbours = vec! [vec! []; names.len()];
+
+        for (wire, &(a, b)) in wires.iter().enumerate() {
+            neighbours[a].push((b, wire));
+            neighbours[b].push((a, wire));
+        }
+
+        Self { names, wires, neighbours }
+    }
+
+    pub fn name(&self, component: usize) -> &str {
+        &self.names[component]
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Pushes up to `limit` units of flow from `source` to `sink`, with every
+    /// wire carrying one unit either way. Returns the flow, and which
+    /// components are still reachable from `source` afterwards.
+    fn max_flow(&self, source: usize, sink: usize, limit: usize) -> (usize, Vec<bool>) {
+        // the flow along each wire, from its first component to its second
+        let mut flow = vec! [0i32; self.wires.len()];
+        let residual = |flow: &[i32], from: usize, wire: usize| if self.wires[wire].0 == from { 1 - flow[wire] } else { 1 + flow[wire] };
+
+        let mut total = 0;
+
+        loop {
+            let mut parents = vec! [None; self.len()];
+            let mut remaining = VecDeque::from([source]);
+            let mut reached = vec! [false; self.len()];
+
+            reached[source] = true;
+
+            while let Some(from) = remaining.pop_front() {
+                for &(to, wire) in &self.neighbours[from] {
+                    if !reached[to] && residual(&flow, from, wire) > 0 {
+                        reached[to] = true;
+                        parents[to] = Some((from, wire));
+                        remaining.push_back(to);
+                    }
+                }
+            }
+
+            if !reached[sink] || total == limit {
+                return (total, reached);
+            }
+
+            let mut to = sink;
+
+            while let Some((from, wire)) = parents[to] {
+                flow[wire] += if self.wires[wire].0 == from { 1 } else { -1 };
+                to = from;
+            }
+
+            total += 1;
+        }
+    }
+
+    /// Finds a cut of exactly `size` wires by computing the maximum flow from
+    /// the first component to every other in turn, stopping early once it
+    /// is too large, so that the result is always the same.
+    pub fn min_cut(&self, size: usize) -> Result<Cut, SolveError> {
+        (1..self.len())
+            .map(|sink| self.max_flow(0, sink, size + 1))
+            .find(|&(flow, _)| flow == size)
+            .map(|(_, reached)| {
+                let wires = self.wires.iter().copied().filter(|&(a, b)| reached[a] != reached[b]).collect();
+                let (inside, outside) = (0..self.len()).partition(|&component| reached[component]);
+
+                Cut { wires, partitions: [inside, outside] }
+            })
+            .ok_or_else(|| SolveError::UnreachableTarget(format!("a cut of {} wires", size)))
+    }
+}
+
+/// The number of wires to disconnect in part 1.
+const CUT_SIZE: usize = 3;
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = Wiring;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Wiring::new(&parse_lines(input, str::parse::<Component>)?))
+    }
+
+    fn part1(wiring: &Self::Input) -> Result<usize, SolveError> {
+        let [a, b] = wiring.min_cut(CUT_SIZE)?.partitions;
+
+        Ok(a.len() * b.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINES: [&str; 13] = [
+        "jqt: rhn xhk nvd",
+        "rsh: frs pzl lsr",
+        "xhk: hfx",
+        "cmg: qnr nvd lhk bvb",
+        "rhn: xhk bvb hfx",
+        "bvb: xhk hfx",
+        "pzl: lsr hfx nvd",
+        "qnr: nvd",
+        "ntq: jqt hfx bvb xhk",
+        "nvd: lhk",
+        "lsr: lhk",
+        "rzs: qnr cmg lsr rsh",
+        "frs: qnr lhk lsr",
+    ];
+
+    #[test]
+    fn _01() {
+        let wiring = Day25::parse(&LINES.join("\n")).unwrap();
+
+        assert_eq!(Day25::part1(&wiring), Ok(54));
+    }
+
+    #[test]
+    fn cut() {
+        let wiring = Day25::parse(&LINES.join("\n")).unwrap();
+        let cut = wiring.min_cut(3).unwrap();
+        let names = |components: &[usize]| {
+            let mut names = components.iter().map(|&c| wiring.name(c)).collect::<Vec<_>>();
+
+            names.sort_unstable();
+            names
+        };
+        let mut wires = cut.wires.iter().map(|&(a, b)| [wiring.name(a), wiring.name(b)]).map(|mut wire| { wire.sort_unstable(); wire }).collect::<Vec<_>>();
+
+        wires.sort_unstable();
+
+        assert_eq!(wires, vec! [["bvb", "cmg"], ["hfx", "pzl"], ["jqt", "nvd"]]);
+        assert_eq!(names(&cut.partitions[0]), vec! ["bvb", "hfx", "jqt", "ntq", "rhn", "xhk"]);
+        assert_eq!(cut.partitions[1].len(), 9);
+        assert_eq!(wiring.min_cut(3), Ok(cut));
+        assert_eq!(wiring.min_cut(2), Err(SolveError::UnreachableTarget("a cut of 2 wires".to_string())));
+    }
+}
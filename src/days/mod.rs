@@ -0,0 +1,141 @@
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day19;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+use std::error::Error;
+
+use crate::{render_part, solve_part1, solve_part2};
+
+/// Solves one part of a puzzle, given the raw puzzle input.
+pub type Solver = fn(&str) -> Result<String, Box<dyn Error>>;
+
+/// Draws how one part of a puzzle was solved, given the raw puzzle input and
+/// the part.
+pub type Renderer = fn(&str, u32) -> Result<Option<String>, Box<dyn Error>>;
+
+pub struct Day {
+    pub number: u32,
+    pub part1: Solver,
+    pub part2: Option<Solver>,
+    pub render: Renderer,
+}
+
+impl Day {
+    pub fn part(&self, part: u32) -> Option<Solver> {
+        match part {
+            1 => Some(self.part1),
+            2 => self.part2,
+            _ => None,
+        }
+    }
+}
+
+macro_rules! day {
+    ($number:literal, $solution:ty) => {
+        Day {
+            number: $number,
+            part1: solve_part1::<$solution>,
+            part2: Some(solve_part2::<$solution>),
+            render: render_part::<$solution>,
+        }
+    };
+    ($number:literal, $solution:ty, part1) => {
+        Day {
+            number: $number,
+            part1: solve_part1::<$solution>,
+            part2: None,
+            render: render_part::<$solution>,
+        }
+    };
+}
+
+pub static DAYS: [Day; 25] = [
+    day!(1, day01::Day01),
+    day!(2, day02::Day02),
+    day!(3, day03::Day03),
+    day!(4, day04::Day04),
+    day!(5, day05::Day05),
+    day!(6, day06::Day06),
+    day!(7, day07::Day07),
+    day!(8, day08::Day08),
+    day!(9, day09::Day09),
+    day!(10, day10::Day10),
+    day!(11, day11::Day11),
+    day!(12, day12::Day12),
+    day!(13, day13::Day13),
+    day!(14, day14::Day14),
+    day!(15, day15::Day15),
+    day!(16, day16::Day16),
+    day!(17, day17::Day17),
+    day!(18, day18::Day18),
+    day!(19, day19::Day19),
+    day!(20, day20::Day20),
+    day!(21, day21::Day21),
+    day!(22, day22::Day22),
+    day!(23, day23::Day23),
+    day!(24, day24::Day24),
+    // the last star is awarded for collecting all the others, there is no part 2
+    day!(25, day25::Day25, part1),
+];
+
+pub fn get(number: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_all() {
+        for number in 1..=25 {
+            assert_eq!(get(number).map(|day| day.number), Some(number));
+        }
+
+        assert!(get(0).is_none());
+        assert!(get(26).is_none());
+    }
+
+    #[test]
+    fn parts() {
+        assert!(get(1).unwrap().part(2).is_some());
+        assert!(get(1).unwrap().part(3).is_none());
+        assert!(get(25).unwrap().part(2).is_none());
+    }
+
+    #[test]
+    fn render() {
+        assert!((get(1).unwrap().render)("1abc2\n", 1).unwrap().is_none());
+        assert!((get(17).unwrap().render)("11\n11\n", 1).unwrap().is_some());
+    }
+
+    #[test]
+    fn solve() {
+        let input = "1abc2\npqr3stu8vwx\na1b2c3d4e5f\ntreb7uchet\n";
+
+        assert_eq!((get(1).unwrap().part1)(input).unwrap(), "142");
+        assert_eq!(solve_part1::<day01::Day01>(input).unwrap(), "142");
+    }
+}
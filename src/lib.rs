@@ -1 +1,5 @@
-pub mod prelude;
\ No newline at end of file
+pub mod days;
+pub mod prelude;
+mod solution;
+
+pub use self::solution::*;
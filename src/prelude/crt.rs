@@ -0,0 +1,60 @@
+use crate::prelude::extended_gcd;
+
+/// Solves the congruences `n = remainder (mod modulus)` together, where the
+/// moduli need not be coprime. Returns the smallest solution and the modulus
+/// that every other solution differs from it by, or `None` if there is no
+/// solution, a modulus is zero, or the combined modulus does not fit in a
+/// `u64`.
+pub fn crt(congruences: impl IntoIterator<Item=(u64, u64)>) -> Option<(u64, u64)> {
+    congruences.into_iter().try_fold((0, 1), |(r1, m1), (r2, m2)| {
+        if m2 == 0 {
+            return None;
+        }
+
+        let (g, p, _) = extended_gcd(m1 as i128, m2 as i128);
+        let difference = r2 as i128 - r1 as i128;
+
+        if difference % g != 0 {
+            return None;
+        }
+
+        // n = r1 + m1 * t, where m1 * t = r2 - r1 (mod m2)
+        let m = m2 as i128 / g;
+        let t = (difference / g).rem_euclid(m) as u128 * p.rem_euclid(m) as u128 % m as u128;
+        let modulus = u64::try_from(m1 as u128 * m as u128).ok()?;
+
+        Some(((r1 as u128 + m1 as u128 * t) as u64 % modulus, modulus))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::mod_inverse;
+
+    #[test]
+    fn coprime() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(0, 5), (0, 7)]), Some((0, 35)));
+        assert_eq!(crt([]), Some((0, 1)));
+        assert_eq!(crt([(1, 0)]), None);
+    }
+
+    #[test]
+    fn non_coprime() {
+        assert_eq!(crt([(1, 4), (3, 6)]), Some((9, 12)));
+        assert_eq!(crt([(1, 2), (2, 4)]), None);
+        assert_eq!(crt([(7, 10), (17, 20)]), Some((17, 20)));
+        assert_eq!(crt([(u64::MAX - 1, u64::MAX), (0, 2)]), None);
+        assert_eq!(crt([(u64::MAX - 1, u64::MAX), (1, 1)]), Some((u64::MAX - 1, u64::MAX)));
+    }
+
+    #[test]
+    fn inverse() {
+        assert_eq!(extended_gcd(240, 46), (2, -9, 47));
+        assert_eq!(extended_gcd(-4, 6), (2, 1, 1));
+        assert_eq!(mod_inverse(3, 11), Some(4));
+        assert_eq!(mod_inverse(4, 10), None);
+        assert_eq!(mod_inverse(5, 1), Some(0));
+    }
+}
@@ -9,3 +9,37 @@ pub fn gcd(mut a: u64, mut b: u64) -> u64
 
     a
 }
+
+/// Returns `(g, x, y)` such that `g` is the greatest common divisor of `a`
+/// and `b`, and `a * x + b * y = g`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+
+    while r1 != 0 {
+        let q = r0 / r1;
+
+        (r0, r1) = (r1, r0 - q * r1);
+        (x0, x1) = (x1, x0 - q * x1);
+        (y0, y1) = (y1, y0 - q * y1);
+    }
+
+    if r0 < 0 {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// The number `x` in `0..modulus` such that `a * x = 1 (mod modulus)`, if `a`
+/// and `modulus` are coprime.
+pub fn mod_inverse(a: u64, modulus: u64) -> Option<u64> {
+    if modulus == 0 {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(a as i128, modulus as i128);
+
+    (g == 1).then(|| x.rem_euclid(modulus as i128) as u64)
+}
@@ -0,0 +1,210 @@
+use std::{fmt::{self, Display}, ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign}};
+
+/// A position on the plane. When used with a [`Grid`](crate::prelude::Grid)
+/// `x` is the column and `y` is the row, so `y` grows downwards.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+/// The displacement between two [`Point2`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output=T> + Add<Output=T>> Point2<T> {
+    pub fn manhattan_distance(&self, other: &Self) -> T {
+        let dx = self.x.max(other.x) - self.x.min(other.x);
+        let dy = self.y.max(other.y) - self.y.min(other.y);
+
+        dx + dy
+    }
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Copy + Ord + Default + Neg<Output=T> + Add<Output=T>> Vec2<T> {
+    pub fn manhattan_length(&self) -> T {
+        let abs = |v: T| if v < T::default() { -v } else { v };
+
+        abs(self.x) + abs(self.y)
+    }
+}
+
+impl<T: Display> Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T: Add<Output=T>> Add<Vec2<T>> for Point2<T> {
+    type Output = Self;
+
+    fn add(self, other: Vec2<T>) -> Self::Output {
+        Self { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+impl<T: Sub<Output=T>> Sub<Vec2<T>> for Point2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Vec2<T>) -> Self::Output {
+        Self { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+impl<T: Sub<Output=T>> Sub for Point2<T> {
+    type Output = Vec2<T>;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Vec2 { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+impl<T: AddAssign> AddAssign<Vec2<T>> for Point2<T> {
+    fn add_assign(&mut self, other: Vec2<T>) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign<Vec2<T>> for Point2<T> {
+    fn sub_assign(&mut self, other: Vec2<T>) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Add<Output=T>> Add for Vec2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+impl<T: Sub<Output=T>> Sub for Vec2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+impl<T: Copy + Mul<Output=T>> Mul<T> for Vec2<T> {
+    type Output = Self;
+
+    fn mul(self, scale: T) -> Self::Output {
+        Self { x: self.x * scale, y: self.y * scale }
+    }
+}
+
+impl<T: Neg<Output=T>> Neg for Vec2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self { x: -self.x, y: -self.y }
+    }
+}
+
+/// One of the four cardinal directions, where north is towards smaller `y`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// All directions, clockwise from north.
+    pub const ALL: [Self; 4] = [Self::North, Self::East, Self::South, Self::West];
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Self::North => Self::West,
+            Self::East => Self::North,
+            Self::South => Self::East,
+            Self::West => Self::South,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Self::North => Self::East,
+            Self::East => Self::South,
+            Self::South => Self::West,
+            Self::West => Self::North,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Self::North => Self::South,
+            Self::East => Self::West,
+            Self::South => Self::North,
+            Self::West => Self::East,
+        }
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Self::East | Self::West)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        !self.is_horizontal()
+    }
+
+    pub fn delta(self) -> Vec2<i32> {
+        match self {
+            Self::North => Vec2::new(0, -1),
+            Self::East => Vec2::new(1, 0),
+            Self::South => Vec2::new(0, 1),
+            Self::West => Vec2::new(-1, 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Point2::new(1, 2);
+        let b = Point2::new(4, -2);
+
+        assert_eq!(b - a, Vec2::new(3, -4));
+        assert_eq!(a + (b - a), b);
+        assert_eq!(b - (b - a), a);
+        assert_eq!(Vec2::new(3, -4) * 2, Vec2::new(6, -8));
+        assert_eq!(-Vec2::new(3, -4), Vec2::new(-3, 4));
+        assert_eq!(a.manhattan_distance(&b), 7);
+        assert_eq!((b - a).manhattan_length(), 7);
+    }
+
+    #[test]
+    fn directions() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+            assert_eq!(direction.delta() + direction.opposite().delta(), Vec2::new(0, 0));
+        }
+
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Point2::new(0, 0) + Direction::North.delta(), Point2::new(0, -1));
+    }
+}
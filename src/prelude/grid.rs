@@ -0,0 +1,269 @@
+use std::{fmt::{self, Display}, ops::{Index, IndexMut}};
+
+use crate::prelude::{Direction, ParseError, Point2, Vec2};
+
+const NEIGHBOURS_8: [Vec2<i32>; 8] = [
+    Vec2::new(-1, -1), Vec2::new(0, -1), Vec2::new(1, -1), Vec2::new(1, 0),
+    Vec2::new(1, 1), Vec2::new(0, 1), Vec2::new(-1, 1), Vec2::new(-1, 0),
+];
+
+/// A dense, row-major grid indexed by signed points, where `x` is the column
+/// and `y` is the row. Anything outside of `0..cols` and `0..rows` is out of
+/// bounds.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    data: Vec<T>,
+    rows: usize,
+    cols: usize,
+}
+
+impl<T> Index<Point2<i32>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point2<i32>) -> &Self::Output {
+        self.get(point).unwrap_or_else(|| panic!("{} is outside of a {}x{} grid", point, self.cols, self.rows))
+    }
+}
+
+impl<T> IndexMut<Point2<i32>> for Grid<T> {
+    fn index_mut(&mut self, point: Point2<i32>) -> &mut Self::Output {
+        let (rows, cols) = (self.rows, self.cols);
+
+        self.get_mut(point).unwrap_or_else(|| panic!("{} is outside of a {}x{} grid", point, cols, rows))
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in 0..self.rows {
+            if row > 0 {
+                writeln!(f)?;
+            }
+
+            for value in self.row(row) {
+                write!(f, "{}", value)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Grid<char> {
+    /// Parses one character per cell, where every character must be one of
+    /// `allowed`.
+    pub fn parse_chars<'a>(lines: impl IntoIterator<Item=&'a str>, allowed: &str) -> Result<Self, ParseError> {
+        Self::parse(lines, |ch| Some(ch).filter(|&ch| allowed.contains(ch)), &format!("one of `{}`", allowed))
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(rows: usize, cols: usize, value: T) -> Self where T: Clone {
+        Self { data: vec! [value; rows * cols], rows, cols }
+    }
+
+    /// Returns `None` if the rows are not all of the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let cols = rows.first().map(|row| row.len()).unwrap_or(0);
+
+        if rows.iter().any(|row| row.len() != cols) {
+            None
+        } else {
+            Some(Self { rows: rows.len(), cols, data: rows.into_iter().flatten().collect() })
+        }
+    }
+
+    /// Parses one character per cell using `cell`, where each line is a row.
+    pub fn parse<'a>(
+        lines: impl IntoIterator<Item=&'a str>,
+        mut cell: impl FnMut(char) -> Option<T>,
+        expected: &str
+    ) -> Result<Self, ParseError>
+    {
+        let mut data = vec! [];
+        let mut rows = 0;
+        let mut cols = None;
+
+        for (i, line) in lines.into_iter().enumerate() {
+            for (j, ch) in line.char_indices() {
+                let value = cell(ch).ok_or_else(|| ParseError::new(line, &line[j..j + ch.len_utf8()], expected).at_line(i + 1))?;
+
+                data.push(value);
+            }
+
+            let width = line.chars().count();
+
+            if *cols.get_or_insert(width) != width {
+                return Err(ParseError::new(line, line, format!("a row of {} cells", cols.unwrap())).at_line(i + 1));
+            }
+
+            rows += 1;
+        }
+
+        Ok(Self { data, rows, cols: cols.unwrap_or(0) })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    fn offset(&self, point: Point2<i32>) -> Option<usize> {
+        if point.x < 0 || point.y < 0 || point.x as usize >= self.cols || point.y as usize >= self.rows {
+            None
+        } else {
+            Some(point.y as usize * self.cols + point.x as usize)
+        }
+    }
+
+    pub fn contains(&self, point: Point2<i32>) -> bool {
+        self.offset(point).is_some()
+    }
+
+    pub fn get(&self, point: Point2<i32>) -> Option<&T> {
+        self.offset(point).map(|i| &self.data[i])
+    }
+
+    pub fn get_mut(&mut self, point: Point2<i32>) -> Option<&mut T> {
+        self.offset(point).map(|i| &mut self.data[i])
+    }
+
+    pub fn points(&self) -> impl Iterator<Item=Point2<i32>> {
+        let cols = self.cols;
+
+        (0..self.rows * self.cols).map(move |i| Point2::new((i % cols) as i32, (i / cols) as i32))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=(Point2<i32>, &T)> {
+        self.points().zip(self.data.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item=(Point2<i32>, &mut T)> {
+        self.points().zip(self.data.iter_mut())
+    }
+
+    /// Returns the first point, in row-major order, whose value matches
+    /// `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Point2<i32>> {
+        self.iter().find(|(_, value)| predicate(value)).map(|(point, _)| point)
+    }
+
+    fn neighbours(&self, point: Point2<i32>, deltas: impl IntoIterator<Item=Vec2<i32>>) -> impl Iterator<Item=(Point2<i32>, &T)> {
+        deltas.into_iter()
+            .map(move |delta| point + delta)
+            .filter_map(move |point| self.get(point).map(|value| (point, value)))
+    }
+
+    /// The in-bounds orthogonal neighbours of `point`, clockwise from north.
+    pub fn neighbours4(&self, point: Point2<i32>) -> impl Iterator<Item=(Point2<i32>, &T)> {
+        self.neighbours(point, Direction::ALL.map(Direction::delta))
+    }
+
+    /// The in-bounds orthogonal and diagonal neighbours of `point`, clockwise
+    /// from north west.
+    pub fn neighbours8(&self, point: Point2<i32>) -> impl Iterator<Item=(Point2<i32>, &T)> {
+        self.neighbours(point, NEIGHBOURS_8)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.data[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn col(&self, col: usize) -> impl DoubleEndedIterator<Item=&T> + ExactSizeIterator {
+        self.data.iter().skip(col).step_by(self.cols.max(1)).take(self.rows)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { data: self.data.iter().map(f).collect(), rows: self.rows, cols: self.cols }
+    }
+
+    pub fn transpose(&self) -> Self where T: Clone {
+        Self {
+            data: (0..self.cols).flat_map(|col| self.col(col).cloned()).collect(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+
+    /// Rotates the grid a quarter turn clockwise.
+    pub fn rotate_right(&self) -> Self where T: Clone {
+        Self {
+            data: (0..self.cols).flat_map(|col| self.col(col).rev().cloned()).collect(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+
+    /// Rotates the grid a quarter turn counter-clockwise.
+    pub fn rotate_left(&self) -> Self where T: Clone {
+        Self {
+            data: (0..self.cols).rev().flat_map(|col| self.col(col).cloned()).collect(),
+            rows: self.cols,
+            cols: self.rows,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINES: [&str; 2] = [
+        "abc",
+        "def",
+    ];
+
+    #[test]
+    fn parse() {
+        let grid = Grid::parse_chars(LINES, "abcdef").unwrap();
+
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid.get(Point2::new(2, 1)), Some(&'f'));
+        assert_eq!(grid.get(Point2::new(0, -1)), None);
+        assert_eq!(grid.get(Point2::new(3, 0)), None);
+        assert_eq!(grid.to_string(), "abc\ndef");
+    }
+
+    #[test]
+    fn parse_error() {
+        let err = Grid::parse_chars(["abc", "dxf"], "abcdef").err().unwrap();
+
+        assert_eq!((err.line(), err.column(), err.text()), (2, 2, "x"));
+
+        let err = Grid::parse_chars(["abc", "de"], "abcdef").err().unwrap();
+
+        assert_eq!((err.line(), err.column(), err.text()), (2, 1, "de"));
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::parse_chars(LINES, "abcdef").unwrap();
+
+        assert_eq!(grid.neighbours4(Point2::new(0, 0)).map(|(_, &ch)| ch).collect::<String>(), "bd");
+        assert_eq!(grid.neighbours4(Point2::new(1, 1)).map(|(_, &ch)| ch).collect::<String>(), "bfd");
+        assert_eq!(grid.neighbours8(Point2::new(1, 1)).map(|(_, &ch)| ch).collect::<String>(), "abcfd");
+    }
+
+    #[test]
+    fn views() {
+        let grid = Grid::parse_chars(LINES, "abcdef").unwrap();
+
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        assert_eq!(grid.col(1).collect::<String>(), "be");
+        assert_eq!(grid.position(|&ch| ch == 'e'), Some(Point2::new(1, 1)));
+    }
+
+    #[test]
+    fn transform() {
+        let grid = Grid::parse_chars(LINES, "abcdef").unwrap();
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_right().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_left().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+        assert_eq!(grid.transpose().transpose(), grid);
+    }
+}
@@ -0,0 +1,108 @@
+use std::ops::{Mul, Range, Sub};
+
+/// An axis-aligned box in `N` dimensions, made up of one half-open range per
+/// axis. The box is empty if any of its ranges is.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HyperRect<T, const N: usize> {
+    ranges: [Range<T>; N],
+}
+
+impl<T: Copy + Ord, const N: usize> HyperRect<T, N> {
+    pub fn new(ranges: [Range<T>; N]) -> Self {
+        Self { ranges }
+    }
+
+    /// A box that contains exactly `point`, given a way to step to the next
+    /// value along an axis.
+    pub fn unit(point: [T; N], next: impl Fn(T) -> T) -> Self {
+        Self { ranges: point.map(|value| value..next(value)) }
+    }
+
+    pub fn empty() -> Self where T: Default {
+        Self { ranges: std::array::from_fn(|_| T::default()..T::default()) }
+    }
+
+    pub fn ranges(&self) -> &[Range<T>; N] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.iter().any(|range| range.is_empty())
+    }
+
+    pub fn contains(&self, point: &[T; N]) -> bool {
+        self.ranges.iter().zip(point).all(|(range, value)| range.contains(value))
+    }
+
+    /// Cuts the box with the plane where `axis` is `value`, into the part
+    /// below it and the part at or above it.
+    pub fn split_at(&self, axis: usize, value: T) -> (Self, Self) {
+        let mut lower = self.clone();
+        let mut upper = self.clone();
+        let range = &self.ranges[axis];
+
+        lower.ranges[axis] = range.start..value.min(range.end).max(range.start);
+        upper.ranges[axis] = value.max(range.start).min(range.end)..range.end;
+
+        (lower, upper)
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = self.clone();
+
+        for (range, other) in intersection.ranges.iter_mut().zip(&other.ranges) {
+            *range = range.start.max(other.start)..range.end.min(other.end);
+        }
+
+        intersection
+    }
+
+    /// The number of points in the box.
+    pub fn volume(&self) -> T where T: Default + Sub<Output = T> + Mul<Output = T> {
+        if self.is_empty() {
+            return T::default();
+        }
+
+        self.ranges.iter()
+            .map(|range| range.end - range.start)
+            .reduce(|volume, length| volume * length)
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split() {
+        let rect = HyperRect::new([0..10, 0..4]);
+        let (lower, upper) = rect.split_at(0, 3);
+
+        assert_eq!(lower, HyperRect::new([0..3, 0..4]));
+        assert_eq!(upper, HyperRect::new([3..10, 0..4]));
+        assert_eq!(lower.volume() + upper.volume(), rect.volume());
+        assert!(rect.split_at(1, 10).1.is_empty());
+        assert!(rect.split_at(1, -1).0.is_empty());
+    }
+
+    #[test]
+    fn intersection() {
+        let a = HyperRect::new([0..10, 0..10, 0..10]);
+        let b = HyperRect::new([5..15, -5..5, 2..3]);
+
+        assert_eq!(a.intersection(&b), HyperRect::new([5..10, 0..5, 2..3]));
+        assert_eq!(a.intersection(&b).volume(), 25);
+        assert_eq!(a.intersection(&HyperRect::new([10..20, 0..10, 0..10])).volume(), 0);
+        assert!(HyperRect::<i32, 3>::empty().is_empty());
+    }
+
+    #[test]
+    fn contains() {
+        let rect = HyperRect::unit([1, 2], |value| value + 1);
+
+        assert_eq!(rect.volume(), 1);
+        assert!(rect.contains(&[1, 2]));
+        assert!(!rect.contains(&[2, 2]));
+    }
+}
@@ -1,10 +1,42 @@
 use crate::prelude::gcd;
 
-pub fn lcm(numbers: &[u64]) -> u64 {
-    numbers.iter().fold(1, |a, b| {
-        let max = a.max(*b);
-        let min = a.min(*b);
+/// The least common multiple of `numbers`, or `None` if it does not fit in a
+/// `u64`.
+pub fn checked_lcm(numbers: &[u64]) -> Option<u64> {
+    numbers.iter().try_fold(1u64, |a, &b| {
+        if b == 0 {
+            Some(0)
+        } else {
+            (a / gcd(a, b)).checked_mul(b)
+        }
+    })
+}
 
-        min * max / gcd(a, *b)
+/// The least common multiple of `numbers` as a `u128`, which is enough for
+/// any two of them, or `None` if it does not fit.
+pub fn lcm_u128(numbers: &[u64]) -> Option<u128> {
+    numbers.iter().try_fold(1u128, |a, &b| {
+        if b == 0 {
+            Some(0)
+        } else {
+            (a / gcd(b, (a % b as u128) as u64) as u128).checked_mul(b as u128)
+        }
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overflow() {
+        let large = [u64::MAX - 1, u64::MAX - 2];
+
+        assert_eq!(checked_lcm(&[4, 6, 10]), Some(60));
+        assert_eq!(checked_lcm(&[3, 0]), Some(0));
+        assert_eq!(checked_lcm(&[1 << 40, 1 << 41, 3 << 20]), Some(3 << 41));
+        assert_eq!(checked_lcm(&large), None);
+        assert_eq!(lcm_u128(&large), Some((u64::MAX - 1) as u128 * (u64::MAX - 2) as u128));
+        assert_eq!(lcm_u128(&[u64::MAX, u64::MAX - 1, u64::MAX - 2]), None);
+    }
+}
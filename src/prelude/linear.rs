@@ -0,0 +1,75 @@
+use crate::prelude::Rational;
+
+/// Brings `matrix` into reduced row echelon form with exact Gaussian
+/// elimination, returning the column of the pivot in each non-zero row. Rows
+/// beyond the number of pivots are left all zero.
+pub fn row_reduce(matrix: &mut [Vec<Rational>]) -> Vec<usize> {
+    let columns = matrix.first().map_or(0, Vec::len);
+    let mut pivots = vec! [];
+
+    for column in 0..columns {
+        let row = pivots.len();
+        let Some(pivot) = (row..matrix.len()).find(|&i| !matrix[i][column].is_zero()) else {
+            continue;
+        };
+
+        matrix.swap(row, pivot);
+
+        let scale = matrix[row][column];
+
+        for value in &mut matrix[row] {
+            *value = *value / scale;
+        }
+
+        let pivot_row = matrix[row].clone();
+
+        for (i, other) in matrix.iter_mut().enumerate() {
+            let factor = other[column];
+
+            if i != row && !factor.is_zero() {
+                for (value, &pivot) in other.iter_mut().zip(&pivot_row).skip(column) {
+                    *value = *value - factor * pivot;
+                }
+            }
+        }
+
+        pivots.push(column);
+
+        if pivots.len() == matrix.len() {
+            break;
+        }
+    }
+
+    pivots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix<const N: usize>(rows: &[[i64; N]]) -> Vec<Vec<Rational>> {
+        rows.iter().map(|row| row.iter().map(|&value| Rational::from(value)).collect()).collect()
+    }
+
+    #[test]
+    fn unique_solution() {
+        // x + y + z = 6, 2y + 5z = -4, 2x + 5y - z = 27
+        let mut system = matrix(&[[1, 1, 1, 6], [0, 2, 5, -4], [2, 5, -1, 27]]);
+
+        assert_eq!(row_reduce(&mut system), vec! [0, 1, 2]);
+        assert_eq!(system, matrix(&[[1, 0, 0, 5], [0, 1, 0, 3], [0, 0, 1, -2]]));
+    }
+
+    #[test]
+    fn rank_deficient() {
+        let mut system = matrix(&[[0, 2, 4], [0, 1, 2], [0, 3, 7]]);
+
+        assert_eq!(row_reduce(&mut system), vec! [1, 2]);
+        assert_eq!(system, matrix(&[[0, 1, 0], [0, 0, 1], [0, 0, 0]]));
+
+        let mut system = vec! [vec! [Rational::new(1, 2), Rational::new(1, 3)]];
+
+        assert_eq!(row_reduce(&mut system), vec! [0]);
+        assert_eq!(system, vec! [vec! [Rational::ONE, Rational::new(2, 3)]]);
+    }
+}
@@ -1,9 +1,27 @@
-mod coo_matrix;
+mod crt;
 mod gcd;
+pub mod geom;
+mod grid;
+mod hyper_rect;
 mod lcm;
+mod linear;
+mod parse_error;
+pub mod search;
+mod rational;
+mod solve_error;
+mod range_map;
 mod range_set;
 
-pub use self::coo_matrix::*;
+pub use self::crt::*;
 pub use self::gcd::*;
+pub use self::geom::*;
+pub use self::grid::*;
+pub use self::hyper_rect::*;
 pub use self::lcm::*;
+pub use self::linear::*;
+pub use self::parse_error::*;
+pub use self::search::*;
+pub use self::rational::*;
+pub use self::solve_error::*;
+pub use self::range_map::*;
 pub use self::range_set::*;
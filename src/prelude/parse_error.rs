@@ -0,0 +1,129 @@
+use std::{error::Error, fmt::{self, Display}, str::FromStr};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    column: usize,
+    text: String,
+    expected: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: expected {}, found `{}`", self.line, self.column, self.expected, self.text)
+    }
+}
+
+impl Error for ParseError {}
+
+impl ParseError {
+    /// Reports that `text` is not what was `expected`. If `text` is a slice
+    /// of `line` then the column points at it, otherwise at the start of the
+    /// line. The line number defaults to `1`, see [`ParseError::at_line`].
+    pub fn new(line: &str, text: &str, expected: impl Into<String>) -> Self {
+        let offset = (text.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+        let column = if offset <= line.len() && offset + text.len() <= line.len() && line.is_char_boundary(offset) {
+            line[..offset].chars().count() + 1
+        } else {
+            1
+        };
+
+        Self {
+            line: 1,
+            column,
+            text: text.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// Reports that the input ended before what was `expected` was found.
+    pub fn end_of_input(expected: impl Into<String>) -> Self {
+        Self::new("", "", expected)
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+}
+
+/// Parses `token`, which should be a slice of `line`, as a `T`.
+pub fn parse_token<T: FromStr>(line: &str, token: &str, expected: &str) -> Result<T, ParseError> {
+    token.parse::<T>().map_err(|_| ParseError::new(line, token, expected))
+}
+
+/// Checks that every character of `line` is one of `allowed`.
+pub fn check_chars(line: &str, allowed: &str) -> Result<(), ParseError> {
+    match line.char_indices().find(|&(_, ch)| !allowed.contains(ch)) {
+        Some((i, ch)) => Err(ParseError::new(line, &line[i..i + ch.len_utf8()], format!("one of `{}`", allowed))),
+        None => Ok(()),
+    }
+}
+
+/// Parses every line of `input` using `parse`, tagging any error with the
+/// line number it occurred on.
+pub fn parse_lines<T>(input: &str, mut parse: impl FnMut(&str) -> Result<T, ParseError>) -> Result<Vec<T>, ParseError> {
+    input.lines()
+        .enumerate()
+        .map(|(i, line)| parse(line).map_err(|err| err.at_line(i + 1)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn column() {
+        let line = "Game 1: 3 blue, 4 rde";
+        let err = ParseError::new(line, &line[18..], "a colour");
+
+        assert_eq!(err.line(), 1);
+        assert_eq!(err.column(), 19);
+        assert_eq!(err.text(), "rde");
+        assert_eq!(err.to_string(), "line 1, column 19: expected a colour, found `rde`");
+    }
+
+    #[test]
+    fn column_of_unrelated_text() {
+        let err = ParseError::new("abc", "xyz", "something");
+
+        assert_eq!(err.column(), 1);
+    }
+
+    #[test]
+    fn chars() {
+        assert_eq!(check_chars("#..#", "#."), Ok(()));
+        assert_eq!(check_chars("#.ö#", "#.").map_err(|err| (err.column(), err.text().to_string())), Err((3, "ö".to_string())));
+    }
+
+    #[test]
+    fn lines() {
+        let input = "1\n2\nthree\n4";
+
+        assert_eq!(
+            parse_lines(input, |line| parse_token::<u32>(line, line, "a number")),
+            Err(ParseError::new("three", "three", "a number").at_line(3))
+        );
+        assert_eq!(
+            parse_lines("1\n2", |line| parse_token::<u32>(line, line, "a number")),
+            Ok(vec! [1, 2])
+        );
+    }
+}
@@ -0,0 +1,181 @@
+use std::{collections::BTreeMap, ops::Range};
+
+use crate::prelude::RangeSet;
+
+/// Associates values with disjoint half-open ranges of keys. Inserting over
+/// an existing range replaces the overlapping part of it and keeps the rest.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RangeMap<K: Ord + Copy, V> {
+    entries: BTreeMap<K, (K, V)>,
+}
+
+impl<K: Ord + Copy, V: Clone> FromIterator<(Range<K>, V)> for RangeMap<K, V> {
+    fn from_iter<I: IntoIterator<Item = (Range<K>, V)>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::new(), |mut map, (range, value)| {
+            map.insert(range, value);
+            map
+        })
+    }
+}
+
+impl<K: Ord + Copy, V> Default for RangeMap<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord + Copy, V> RangeMap<K, V> {
+    pub fn new() -> Self {
+        Self {
+            entries: BTreeMap::new(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The number of ranges in the map.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Range<K>, &V)> {
+        self.entries.iter().map(|(&start, (end, value))| (start..*end, value))
+    }
+
+    pub fn insert(&mut self, range: Range<K>, value: V) where V: Clone {
+        if range.is_empty() {
+            return;
+        }
+
+        self.remove(range.clone());
+        self.entries.insert(range.start, (range.end, value));
+    }
+
+    /// Removes every key in `range`, splitting any range that straddles
+    /// either end of it.
+    pub fn remove(&mut self, range: Range<K>) where V: Clone {
+        if range.is_empty() {
+            return;
+        }
+
+        let overlapping = self.entries.range(..range.end)
+            .rev()
+            .take_while(|(_, (end, _))| *end > range.start)
+            .map(|(&start, _)| start)
+            .collect::<Vec<_>>();
+
+        for start in overlapping {
+            let (end, value) = self.entries.remove(&start).unwrap();
+
+            if start < range.start {
+                self.entries.insert(start, (range.start, value.clone()));
+            }
+
+            if range.end < end {
+                self.entries.insert(range.end, (end, value));
+            }
+        }
+    }
+
+    /// Returns the range containing `key`, and its value.
+    pub fn get_key_value(&self, key: &K) -> Option<(Range<K>, &V)> {
+        self.entries.range(..=key).next_back()
+            .filter(|(_, (end, _))| key < end)
+            .map(|(&start, (end, value))| (start..*end, value))
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.get_key_value(key).map(|(_, value)| value)
+    }
+
+    /// The parts of every range that overlap `range`, in ascending order.
+    pub fn overlapping(&self, range: Range<K>) -> impl Iterator<Item = (Range<K>, &V)> {
+        let from = self.get_key_value(&range.start).map_or(range.start, |(found, _)| found.start);
+
+        self.entries.range(from..)
+            .take_while(move |(&start, _)| start < range.end)
+            .map(move |(&start, (end, value))| (start.max(range.start)..(*end).min(range.end), value))
+            .filter(|(range, _)| !range.is_empty())
+    }
+
+    /// The parts of `within` that are not covered by any range, in ascending
+    /// order.
+    pub fn gaps(&self, within: Range<K>) -> impl Iterator<Item = Range<K>> {
+        let mut gaps = vec! [];
+        let mut start = within.start;
+
+        for (range, _) in self.overlapping(within.clone()) {
+            if start < range.start {
+                gaps.push(start..range.start);
+            }
+
+            start = range.end;
+        }
+
+        if start < within.end {
+            gaps.push(start..within.end);
+        }
+
+        gaps.into_iter()
+    }
+
+    /// Maps every value in `set` through the map, where `f` maps the part of
+    /// a range that overlaps an entry given its value. Values that are not in
+    /// any range are kept as they are.
+    pub fn map_set(&self, set: &RangeSet<K>, mut f: impl FnMut(Range<K>, &V) -> Range<K>) -> RangeSet<K> {
+        let mut output = RangeSet::new();
+
+        for range in set.iter() {
+            output.extend(self.overlapping(range.clone()).map(|(overlap, value)| f(overlap, value)));
+            output.extend(self.gaps(range.clone()));
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn insert() {
+        let mut map = RangeMap::new();
+
+        map.insert(0..10, 'a');
+        map.insert(20..30, 'b');
+        map.insert(5..25, 'c');
+        map.insert(30..30, 'd');
+
+        assert_eq!(map.iter().collect::<Vec<_>>(), vec! [(0..5, &'a'), (5..25, &'c'), (25..30, &'b')]);
+
+        map.insert(1..2, 'e');
+
+        assert_eq!(map.iter().collect::<Vec<_>>(), vec! [(0..1, &'a'), (1..2, &'e'), (2..5, &'a'), (5..25, &'c'), (25..30, &'b')]);
+    }
+
+    #[test]
+    fn lookup() {
+        let map = RangeMap::from_iter([(0..10, 'a'), (20..30, 'b')]);
+
+        assert_eq!(map.get(&0), Some(&'a'));
+        assert_eq!(map.get(&10), None);
+        assert_eq!(map.get_key_value(&25), Some((20..30, &'b')));
+        assert_eq!(map.overlapping(5..25).collect::<Vec<_>>(), vec! [(5..10, &'a'), (20..25, &'b')]);
+        assert_eq!(map.gaps(-5..35).collect::<Vec<_>>(), vec! [-5..0, 10..20, 30..35]);
+        assert_eq!(map.gaps(2..8).count(), 0);
+    }
+
+    #[test]
+    fn map_set() {
+        let map = RangeMap::from_iter([(0..10, 100), (20..30, -20)]);
+        let set = RangeSet::from([5..25, 40..45]);
+
+        assert_eq!(
+            map.map_set(&set, |range, &offset| (range.start + offset)..(range.end + offset)),
+            RangeSet::from([0..5, 10..20, 40..45, 105..110])
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use std::{collections::BTreeSet, ops::Range};
+use std::{collections::BTreeSet, fmt, ops::{Add, Range, Sub}};
 
 #[derive(Clone)]
 struct OrderedRange<T: Copy> {
@@ -63,10 +63,36 @@ impl<T: Copy + Ord> IntoIter<T> {
     }
 }
 
+/// A set of values stored as disjoint, non-adjacent half-open ranges. Empty
+/// ranges are never stored, so two sets containing the same values always
+/// compare equal.
+#[derive(Clone)]
 pub struct RangeSet<T: Ord + Copy> {
     ranges: BTreeSet<OrderedRange<T>>,
 }
 
+impl<T: Ord + Copy> PartialEq for RangeSet<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Ord + Copy> Eq for RangeSet<T> {}
+
+impl<T: Ord + Copy + fmt::Debug> fmt::Debug for RangeSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<T: Ord + Copy> Extend<Range<T>> for RangeSet<T> {
+    fn extend<I: IntoIterator<Item = Range<T>>>(&mut self, ranges: I) {
+        for range in ranges {
+            self.push(range);
+        }
+    }
+}
+
 impl<T: Ord + Copy> FromIterator<Range<T>> for RangeSet<T> {
     fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
         iter.into_iter().fold(Self::new(), |mut set, range| {
@@ -87,7 +113,22 @@ impl<T: Ord + Copy> IntoIterator for RangeSet<T> {
 
 impl<T: Ord + Copy, const N: usize> From<[Range<T>; N]> for RangeSet<T> {
     fn from(ranges: [Range<T>; N]) -> Self {
-        Self::from_iter(ranges.into_iter())
+        Self::from_iter(ranges)
+    }
+}
+
+impl<T: Ord + Copy> From<Range<T>> for RangeSet<T> {
+    fn from(range: Range<T>) -> Self {
+        let mut set = Self::new();
+
+        set.push(range);
+        set
+    }
+}
+
+impl<T: Ord + Copy> Default for RangeSet<T> {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -102,15 +143,21 @@ impl<T: Ord + Copy> RangeSet<T> {
         self.ranges.iter().map(|r| &r.range)
     }
 
+    /// Adds every value in `range`, merging it with any overlapping or
+    /// adjacent ranges.
     pub fn push(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+
         let mut range = OrderedRange::new(range.clone());
 
-        while let Some(overlapping_range) = self.ranges.range(..=range.clone()).next_back().filter(|r| r.end() > range.start()).cloned() {
+        while let Some(overlapping_range) = self.ranges.range(..=range.clone()).next_back().filter(|r| r.end() >= range.start()).cloned() {
             range = OrderedRange::new(overlapping_range.start()..range.end().max(overlapping_range.end()));
             self.ranges.remove(&overlapping_range);
         }
 
-        while let Some(overlapping_range) = self.ranges.range(range.clone()..).next().filter(|r| r.start() < range.end()).cloned() {
+        while let Some(overlapping_range) = self.ranges.range(range.clone()..).next().filter(|r| r.start() <= range.end()).cloned() {
             range = OrderedRange::new(range.start()..overlapping_range.end().max(range.end()));
             self.ranges.remove(&overlapping_range);
         }
@@ -121,7 +168,7 @@ impl<T: Ord + Copy> RangeSet<T> {
     pub fn get(&self, value: &T) -> Option<Range<T>> {
         let range = OrderedRange::new(*value..*value);
 
-        self.ranges.range(..=range).next_back().filter(|r| r.range.contains(&value)).map(|r| r.range.clone())
+        self.ranges.range(..=range).next_back().filter(|r| r.range.contains(value)).map(|r| r.range.clone())
     }
 
     pub fn contains(&self, value: T) -> bool {
@@ -132,17 +179,123 @@ impl<T: Ord + Copy> RangeSet<T> {
         self.ranges.pop_last().map(|r| r.range)
     }
 
-    pub fn extend(&mut self, ranges: impl IntoIterator<Item = Range<T>>) {
-        for range in ranges.into_iter() {
-            self.push(range);
+    /// Removes every value in `range`, splitting any range that straddles
+    /// either end of it.
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let overlapping = self.ranges.range(..OrderedRange::new(range.end..range.end))
+            .rev()
+            .take_while(|r| r.end() > range.start)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        for overlapping_range in overlapping {
+            self.ranges.remove(&overlapping_range);
+
+            for leftover in [overlapping_range.start()..range.start, range.end..overlapping_range.end()] {
+                if !leftover.is_empty() {
+                    self.ranges.insert(OrderedRange::new(leftover));
+                }
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    /// The total number of values in the set.
+    pub fn len(&self) -> T where T: Default + Add<Output = T> + Sub<Output = T> {
+        self.iter().fold(T::default(), |len, range| len + (range.end - range.start))
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+
+        union.extend(other.iter().cloned());
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = Self::new();
+
+        for range in other.iter() {
+            let overlapping = self.ranges.range(..OrderedRange::new(range.end..range.end))
+                .rev()
+                .take_while(|r| r.end() > range.start);
+
+            for overlapping_range in overlapping {
+                intersection.push(overlapping_range.start().max(range.start)..overlapping_range.end().min(range.end));
+            }
         }
+
+        intersection
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+
+        for range in other.iter() {
+            difference.remove(range.clone());
+        }
+
+        difference
+    }
+
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        self.difference(other).union(&other.difference(self))
+    }
+
+    /// Every value in `range` that is not in the set.
+    pub fn complement_within(&self, range: Range<T>) -> Self {
+        Self::from(range).difference(self)
+    }
+
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(other).is_empty()
+    }
+
+    /// Splits the set into the values below `value` and those at or above it.
+    pub fn split_at(&self, value: T) -> (Self, Self) {
+        let mut lower = Self::new();
+        let mut upper = Self::new();
+
+        for range in self.iter() {
+            lower.push(range.start..value.min(range.end));
+            upper.push(value.max(range.start)..range.end);
+        }
+
+        (lower, upper)
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
     use super::*;
 
+    fn random_set(rng: &mut StdRng) -> RangeSet<i32> {
+        (0..rng.gen_range(0..6))
+            .map(|_| {
+                let start = rng.gen_range(0..40);
+
+                start..(start + rng.gen_range(0..10))
+            })
+            .collect()
+    }
+
+    fn model(set: &RangeSet<i32>) -> BTreeSet<i32> {
+        set.iter().flat_map(|range| range.clone()).collect()
+    }
+
+    fn from_model(values: &BTreeSet<i32>) -> RangeSet<i32> {
+        values.iter().map(|&value| value..(value + 1)).collect()
+    }
+
     #[test]
     fn overlapping() {
         let ranges = RangeSet::from([
@@ -179,4 +332,59 @@ mod tests {
             assert_eq!(ranges.contains(i), (0..10).contains(&i) || (20..30).contains(&i));
         }
     }
+
+    #[test]
+    fn adjacent() {
+        let ranges = RangeSet::from([0..10, 10..20, 25..25]);
+
+        assert_eq!(ranges.iter().cloned().collect::<Vec<_>>(), vec! [0..20]);
+        assert_eq!(ranges, RangeSet::from([0..5, 5..20]));
+        assert_eq!(format!("{:?}", ranges), "{0..20}");
+    }
+
+    #[test]
+    fn remove() {
+        let mut ranges = RangeSet::from([0..10, 20..30]);
+
+        ranges.remove(5..25);
+
+        assert_eq!(ranges, RangeSet::from([0..5, 25..30]));
+        assert_eq!(ranges.len(), 10);
+        assert_eq!(ranges.split_at(3), (RangeSet::from(0..3), RangeSet::from([3..5, 25..30])));
+        assert_eq!(ranges.complement_within(0..40), RangeSet::from([5..25, 30..40]));
+    }
+
+    #[test]
+    fn matches_model() {
+        let mut rng = StdRng::seed_from_u64(2023);
+
+        for _ in 0..500 {
+            let (a, b) = (random_set(&mut rng), random_set(&mut rng));
+            let (model_a, model_b) = (model(&a), model(&b));
+            let value = rng.gen_range(-5..50);
+
+            assert_eq!(model(&a.union(&b)), &model_a | &model_b);
+            assert_eq!(model(&a.intersection(&b)), &model_a & &model_b);
+            assert_eq!(model(&a.difference(&b)), &model_a - &model_b);
+            assert_eq!(model(&a.symmetric_difference(&b)), &model_a ^ &model_b);
+            assert_eq!(model(&a.complement_within(10..30)), (10..30).filter(|v| !model_a.contains(v)).collect());
+            assert_eq!(a.is_disjoint(&b), model_a.is_disjoint(&model_b));
+            assert_eq!(a.len() as usize, model_a.len());
+            assert_eq!(a, from_model(&model_a));
+            assert_eq!(a.contains(value), model_a.contains(&value));
+
+            let (lower, upper) = a.split_at(value);
+
+            assert_eq!(model(&lower), model_a.iter().copied().filter(|&v| v < value).collect());
+            assert_eq!(model(&upper), model_a.iter().copied().filter(|&v| v >= value).collect());
+
+            let mut removed = a.clone();
+
+            for range in b.iter() {
+                removed.remove(range.clone());
+            }
+
+            assert_eq!(removed, a.difference(&b));
+        }
+    }
 }
@@ -0,0 +1,178 @@
+use std::{cmp::Ordering, fmt::{self, Display}, ops::{Add, Div, Mul, Neg, Sub}};
+
+use crate::prelude::extended_gcd;
+
+/// An exact fraction, always kept in lowest terms with a positive
+/// denominator so that equal values compare equal.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: i128,
+    denominator: i128,
+}
+
+impl Rational {
+    pub const ZERO: Self = Self { numerator: 0, denominator: 1 };
+    pub const ONE: Self = Self { numerator: 1, denominator: 1 };
+
+    pub fn new(numerator: i128, denominator: i128) -> Self {
+        assert!(denominator != 0, "rational with a zero denominator");
+
+        let (g, _, _) = extended_gcd(numerator, denominator);
+        let g = if denominator < 0 { -g } else { g };
+
+        Self { numerator: numerator / g, denominator: denominator / g }
+    }
+
+    pub fn numerator(&self) -> i128 {
+        self.numerator
+    }
+
+    pub fn denominator(&self) -> i128 {
+        self.denominator
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.numerator == 0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.numerator < 0
+    }
+
+    /// The value as an integer, if it is one.
+    pub fn to_integer(&self) -> Option<i128> {
+        (self.denominator == 1).then_some(self.numerator)
+    }
+
+    /// The nearest `f64`, for display and other inexact uses.
+    pub fn to_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Self { numerator: value as i128, denominator: 1 }
+    }
+}
+
+impl From<i128> for Rational {
+    fn from(value: i128) -> Self {
+        Self { numerator: value, denominator: 1 }
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denominator == 1 {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    /// Compares the whole parts first, so that only the fractional parts,
+    /// which are smaller than their denominators, are ever multiplied.
+    fn cmp(&self, other: &Self) -> Ordering {
+        let whole = |r: &Self| (r.numerator.div_euclid(r.denominator), r.numerator.rem_euclid(r.denominator));
+        let (a, a_rest) = whole(self);
+        let (b, b_rest) = whole(other);
+
+        a.cmp(&b).then_with(|| (a_rest * other.denominator).cmp(&(b_rest * self.denominator)))
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self { numerator: -self.numerator, denominator: self.denominator }
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        let (g, _, _) = extended_gcd(self.denominator, other.denominator);
+
+        Self::new(
+            self.numerator * (other.denominator / g) + other.numerator * (self.denominator / g),
+            self.denominator / g * other.denominator
+        )
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + -other
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        // cancel across first to keep the intermediate products small
+        let (a, _, _) = extended_gcd(self.numerator, other.denominator);
+        let (b, _, _) = extended_gcd(other.numerator, self.denominator);
+        let (a, b) = (a.max(1), b.max(1));
+
+        Self::new(
+            (self.numerator / a) * (other.numerator / b),
+            (self.denominator / b) * (other.denominator / a)
+        )
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        assert!(!other.is_zero(), "division of a rational by zero");
+
+        self * Self::new(other.denominator, other.numerator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let half = Rational::new(1, 2);
+        let third = Rational::new(-2, -6);
+
+        assert_eq!(Rational::new(4, -8), -half);
+        assert_eq!(half + third, Rational::new(5, 6));
+        assert_eq!(half - third, Rational::new(1, 6));
+        assert_eq!(half * third, Rational::new(1, 6));
+        assert_eq!(half / third, Rational::new(3, 2));
+        assert_eq!(Rational::new(6, 3).to_integer(), Some(2));
+        assert_eq!(half.to_integer(), None);
+        assert_eq!((Rational::ZERO * half).denominator(), 1);
+        assert_eq!(format!("{} {}", Rational::new(-3, 6), Rational::from(7i64)), "-1/2 7");
+    }
+
+    #[test]
+    fn ordering() {
+        let large = Rational::new(i128::MAX / 2, 7);
+
+        assert!(Rational::new(-1, 2) < Rational::new(-1, 3));
+        assert!(Rational::new(7, 3) > Rational::from(2i64));
+        assert!(large > Rational::new(i128::MAX / 2, 11));
+        assert!(large < Rational::new(i128::MAX / 2 + 1, 7));
+        assert_eq!(Rational::new(2, 4).cmp(&Rational::new(1, 2)), Ordering::Equal);
+    }
+}
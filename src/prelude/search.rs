@@ -0,0 +1,153 @@
+use std::{cmp::Ordering, collections::{BinaryHeap, HashMap}, hash::Hash, ops::Add};
+
+/// A state in the frontier, ordered so that the `BinaryHeap` pops the lowest
+/// `priority` first.
+struct Entry<S, C> {
+    priority: C,
+    cost: C,
+    state: S,
+}
+
+impl<S, C: PartialEq> PartialEq for Entry<S, C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<S, C: Eq> Eq for Entry<S, C> {}
+
+impl<S, C: Ord> PartialOrd for Entry<S, C> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<S, C: Ord> Ord for Entry<S, C> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+/// The cheapest way to reach a target state, found by [`dijkstra`] or
+/// [`astar`]. The path is only reconstructed when asked for.
+pub struct Found<S, C> {
+    cost: C,
+    target: S,
+    parents: HashMap<S, (C, Option<S>)>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Found<S, C> {
+    pub fn cost(&self) -> C {
+        self.cost
+    }
+
+    pub fn target(&self) -> &S {
+        &self.target
+    }
+
+    /// All states from the start up to, and including, the target.
+    pub fn path(&self) -> Vec<S> {
+        let mut path = vec! [self.target.clone()];
+
+        while let Some((_, Some(parent))) = self.parents.get(path.last().unwrap()) {
+            path.push(parent.clone());
+        }
+
+        path.reverse();
+        path
+    }
+}
+
+/// Finds the cheapest path from any of `starts` to a state matching
+/// `is_target`, where `successors` yields the neighbouring states and the cost
+/// of moving to each of them.
+pub fn dijkstra<S, C, I>(
+    starts: impl IntoIterator<Item=S>,
+    successors: impl FnMut(&S) -> I,
+    is_target: impl FnMut(&S) -> bool
+) -> Option<Found<S, C>>
+    where S: Clone + Eq + Hash,
+          C: Copy + Ord + Default + Add<Output=C>,
+          I: IntoIterator<Item=(S, C)>
+{
+    astar(starts, successors, |_| C::default(), is_target)
+}
+
+/// Like [`dijkstra`], but guided by a `heuristic` that must never overestimate
+/// the remaining cost to the nearest target.
+pub fn astar<S, C, I>(
+    starts: impl IntoIterator<Item=S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_target: impl FnMut(&S) -> bool
+) -> Option<Found<S, C>>
+    where S: Clone + Eq + Hash,
+          C: Copy + Ord + Default + Add<Output=C>,
+          I: IntoIterator<Item=(S, C)>
+{
+    let mut parents: HashMap<S, (C, Option<S>)> = HashMap::new();
+    let mut remaining = BinaryHeap::new();
+
+    for state in starts {
+        parents.insert(state.clone(), (C::default(), None));
+        remaining.push(Entry { priority: heuristic(&state), cost: C::default(), state });
+    }
+
+    while let Some(Entry { cost, state, .. }) = remaining.pop() {
+        if parents.get(&state).is_some_and(|&(best, _)| best < cost) {
+            continue;
+        } else if is_target(&state) {
+            return Some(Found { cost, target: state, parents });
+        }
+
+        for (next_state, step_cost) in successors(&state) {
+            let next_cost = cost + step_cost;
+
+            if parents.get(&next_state).is_none_or(|&(best, _)| next_cost < best) {
+                parents.insert(next_state.clone(), (next_cost, Some(state.clone())));
+                remaining.push(Entry { priority: next_cost + heuristic(&next_state), cost: next_cost, state: next_state });
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A small graph where the direct edge from `0` to `3` is not the
+    /// cheapest way there.
+    fn successors(&state: &u32) -> Vec<(u32, u32)> {
+        match state {
+            0 => vec! [(1, 1), (3, 10)],
+            1 => vec! [(2, 2), (0, 1)],
+            2 => vec! [(3, 3)],
+            _ => vec! [],
+        }
+    }
+
+    #[test]
+    fn shortest_path() {
+        let found = dijkstra([0], successors, |&state| state == 3).unwrap();
+
+        assert_eq!(found.cost(), 6);
+        assert_eq!(found.target(), &3);
+        assert_eq!(found.path(), vec! [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn heuristic() {
+        let found = astar([0], successors, |&state| 3 - state, |&state| state == 3).unwrap();
+
+        assert_eq!(found.cost(), 6);
+        assert_eq!(found.path(), vec! [0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn unreachable() {
+        assert!(dijkstra([2], successors, |&state| state == 0).is_none());
+        assert_eq!(dijkstra([3], successors, |&state| state == 3).map(|found| found.path()), Some(vec! [3]));
+    }
+}
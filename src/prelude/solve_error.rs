@@ -0,0 +1,32 @@
+use std::{error::Error, fmt::{self, Display}};
+
+/// Reasons that a solver could not derive an answer from an otherwise
+/// well-formed puzzle input.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SolveError {
+    UnreachableTarget(String),
+    MissingReference(String),
+    UnsatisfiableSystem(String),
+    Cycle(String),
+    MissingFeature(String),
+    MissingPart(u32),
+    MissingValue(String),
+    Overflow(String),
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnreachableTarget(target) => write!(f, "{} is unreachable", target),
+            Self::MissingReference(name) => write!(f, "reference to undefined `{}`", name),
+            Self::UnsatisfiableSystem(system) => write!(f, "{} has no solution", system),
+            Self::Cycle(name) => write!(f, "`{}` refers back to itself", name),
+            Self::MissingFeature(feature) => write!(f, "built without the `{}` feature", feature),
+            Self::MissingPart(part) => write!(f, "there is no part {}", part),
+            Self::MissingValue(value) => write!(f, "missing {}", value),
+            Self::Overflow(value) => write!(f, "{} overflows", value),
+        }
+    }
+}
+
+impl Error for SolveError {}
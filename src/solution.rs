@@ -0,0 +1,39 @@
+use std::{error::Error, fmt::Display};
+
+use crate::prelude::{ParseError, SolveError};
+
+/// A single day of the calendar, split into a parse stage that is shared by
+/// both parts, and the two parts themselves.
+pub trait Solution {
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part1(input: &Self::Input) -> Result<Self::Part1, SolveError>;
+
+    /// Days without a second puzzle leave this out, and are registered
+    /// without a part 2 solver.
+    fn part2(_input: &Self::Input) -> Result<Self::Part2, SolveError> {
+        Err(SolveError::MissingPart(2))
+    }
+
+    /// Draws how `part` was solved, for the days where that is worth seeing.
+    fn render(_input: &Self::Input, _part: u32) -> Option<Result<String, SolveError>> {
+        None
+    }
+}
+
+pub fn solve_part1<S: Solution>(input: &str) -> Result<String, Box<dyn Error>> {
+    Ok(S::part1(&S::parse(input)?)?.to_string())
+}
+
+pub fn solve_part2<S: Solution>(input: &str) -> Result<String, Box<dyn Error>> {
+    Ok(S::part2(&S::parse(input)?)?.to_string())
+}
+
+/// Returns `None` if the day has nothing to draw.
+pub fn render_part<S: Solution>(input: &str, part: u32) -> Result<Option<String>, Box<dyn Error>> {
+    Ok(S::render(&S::parse(input)?, part).transpose()?)
+}